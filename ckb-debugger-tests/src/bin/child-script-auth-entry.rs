@@ -0,0 +1,59 @@
+// child-script-example with the ckb-auth entry in version 1 or 2 args, run by
+// exec, dynamic linking or spawn.
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{
+    create_auth_args_v1, create_auth_args_v2, create_script_from_cell_dep, generate_sighash_all,
+    hash::blake160, read_tx_template, ENTRY_CATEGORY_DYNAMIC_LINKING, ENTRY_CATEGORY_EXEC,
+    ENTRY_CATEGORY_SPAWN,
+};
+use ckb_jsonrpc_types::JsonBytes;
+use ckb_types::{bytes::Bytes, packed::WitnessArgsBuilder, prelude::*, H256};
+
+static G_PRIVKEY_BUF: [u8; 32] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+];
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    // ckb-auth is at cell_deps[1]
+    let (use_type, entry_category, v2) = match args[1].as_str() {
+        "exec" => (false, ENTRY_CATEGORY_EXEC, false),
+        "dynamic-linking" => (false, ENTRY_CATEGORY_DYNAMIC_LINKING, false),
+        "dynamic-linking-by-type" => (true, ENTRY_CATEGORY_DYNAMIC_LINKING, false),
+        "exec-v2" => (false, ENTRY_CATEGORY_EXEC, true),
+        "dynamic-linking-v2" => (false, ENTRY_CATEGORY_DYNAMIC_LINKING, true),
+        "spawn" => (false, ENTRY_CATEGORY_SPAWN, false),
+        "spawn-by-type" => (true, ENTRY_CATEGORY_SPAWN, false),
+        _ => unreachable!(),
+    };
+    let private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let pubkey_hash = blake160(&private_key.pubkey().expect("pubkey").serialize());
+
+    let mut tx = read_tx_template("../ckb-debugger-tests/templates/child-script-success.json")?;
+    let entry = create_script_from_cell_dep(&tx, 1, use_type)?;
+    let auth_args = if v2 {
+        create_auth_args_v2(0, &pubkey_hash, &entry, entry_category)
+    } else {
+        create_auth_args_v1(0, &pubkey_hash, &entry, entry_category)
+    };
+    tx.mock_info.inputs[0].output.lock.args = JsonBytes::from_vec(auth_args);
+
+    let message = generate_sighash_all(&tx, 0)?;
+    let sig = private_key
+        .sign_recoverable(&H256::from(message))
+        .expect("sign")
+        .serialize();
+
+    tx.tx.witnesses.clear();
+    tx.tx.witnesses.push(JsonBytes::from_bytes(
+        WitnessArgsBuilder::default()
+            .lock(Some(Bytes::from(sig)).pack())
+            .build()
+            .as_bytes(),
+    ));
+
+    let json = serde_json::to_string_pretty(&tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,252 @@
+// child-script-example with identities other than CKB. The keys are
+// generated locally from a fixed seed:
+// litecoin:  signature = header(1) | recoverable secp256k1 signature(64), signing
+//     sha256d(0x19 | "Litecoin Signed Message:\n" | 0x40 | hex of message)
+//     pubkey hash = ripemd160(sha256(compressed pubkey))
+// cardano:   signature = CBOR of a signed transaction
+//     [tx body, {0: [[vkey(32), ed25519 signature(64)]]}, true, null]
+//     with the message in the tx body at key 7, signing blake2b-256(tx body)
+// monero:    signature = signature(64) | mode(1) | spend pubkey(32) | view pubkey(32)
+//     signing keccak256(message) by the spend key when mode is 0
+//     pubkey hash = blake160(mode | spend pubkey | view pubkey)
+// solana:    signature = pubkey(32) | ed25519 signature(64)
+// ripple:    signature = serialized signed transaction with the message as
+//     InvoiceID, DER encoded ECDSA signature of
+//     sha512half("STX\0" | transaction without TxnSignature)
+//     pubkey hash = ripemd160(sha256(compressed pubkey))
+// secp256r1: signature = pubkey(64, uncompressed without prefix) | ECDSA-SHA256 signature(64)
+// For cardano, solana and secp256r1, pubkey hash = blake160(pubkey).
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{generate_sighash_all, hash::blake160, read_tx_template};
+use ckb_hash::Blake2bBuilder;
+use ckb_jsonrpc_types::JsonBytes;
+use ckb_mock_tx_types::ReprMockTransaction;
+use ckb_types::{bytes::Bytes, packed::WitnessArgsBuilder, prelude::*, H256};
+use clap::{Parser, ValueEnum};
+use curve25519_dalek::{edwards::EdwardsPoint, scalar::Scalar};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+use sha3::Keccak256;
+
+static G_PRIVKEY_BUF: [u8; 32] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+];
+
+// Same as AuthAlgorithmIdType in ckb-lock-common
+#[derive(Clone, Copy, ValueEnum)]
+enum Algorithm {
+    Litecoin = 10,
+    Cardano = 11,
+    Monero = 12,
+    Solana = 13,
+    Ripple = 14,
+    Secp256r1 = 15,
+}
+
+#[derive(Parser)]
+struct Args {
+    #[arg(long, value_enum)]
+    algorithm: Algorithm,
+}
+
+const LITECOIN_MESSAGE_PREFIX: &[u8] = b"\x19Litecoin Signed Message:\n";
+
+fn litecoin_message(message: &[u8; 32]) -> [u8; 32] {
+    let hex: String = message.iter().map(|b| format!("{:02x}", b)).collect();
+    let mut data = LITECOIN_MESSAGE_PREFIX.to_vec();
+    data.push(hex.len() as u8);
+    data.extend(hex.as_bytes());
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+// CBOR head of `major` type with `len`, enough for the short values here
+fn cbor_head(major: u8, len: usize) -> Vec<u8> {
+    match len {
+        0..=23 => vec![major << 5 | len as u8],
+        _ => vec![major << 5 | 24, len as u8],
+    }
+}
+
+fn cbor_bytes(bytes: &[u8]) -> Vec<u8> {
+    [cbor_head(2, bytes.len()), bytes.to_vec()].concat()
+}
+
+// {0: [], 1: [], 2: 0, 7: message}
+fn cardano_tx_body(message: &[u8; 32]) -> Vec<u8> {
+    let mut body = cbor_head(5, 4);
+    body.extend([0x00, 0x80, 0x01, 0x80, 0x02, 0x00, 0x07]);
+    body.extend(cbor_bytes(message));
+    body
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+// (spend key, view key), the view key is derived from the spend key as
+// Monero wallets do.
+fn monero_keys() -> (Scalar, Scalar) {
+    let spend = Scalar::from_bytes_mod_order(G_PRIVKEY_BUF);
+    let view = Scalar::from_bytes_mod_order(keccak256(spend.as_bytes()));
+    (spend, view)
+}
+
+// TransactionType (Payment), InvoiceID, SigningPubKey and TxnSignature, in
+// the canonical field order.
+fn ripple_tx(message: &[u8; 32], pubkey: &[u8], signature: Option<&[u8]>) -> Vec<u8> {
+    let mut tx = vec![0x12, 0x00, 0x00, 0x50, 0x11];
+    tx.extend(message);
+    tx.extend([0x73, pubkey.len() as u8]);
+    tx.extend(pubkey);
+    if let Some(signature) = signature {
+        tx.extend([0x74, signature.len() as u8]);
+        tx.extend(signature);
+    }
+    tx
+}
+
+fn pubkey_hash(algorithm: Algorithm) -> Vec<u8> {
+    match algorithm {
+        Algorithm::Litecoin | Algorithm::Ripple => {
+            Ripemd160::digest(Sha256::digest(pubkey(algorithm))).to_vec()
+        }
+        _ => blake160(&pubkey(algorithm)).to_vec(),
+    }
+}
+
+fn pubkey(algorithm: Algorithm) -> Vec<u8> {
+    match algorithm {
+        Algorithm::Litecoin | Algorithm::Ripple => {
+            let privkey = Privkey::from(H256::from(G_PRIVKEY_BUF));
+            privkey.pubkey().unwrap().serialize()
+        }
+        Algorithm::Cardano | Algorithm::Solana => {
+            let signing_key = ed25519_dalek::SigningKey::from_bytes(&G_PRIVKEY_BUF);
+            signing_key.verifying_key().to_bytes().to_vec()
+        }
+        // mode 0: signed by the spend key
+        Algorithm::Monero => {
+            let (spend, view) = monero_keys();
+            let mut pubkey = vec![0];
+            pubkey.extend(EdwardsPoint::mul_base(&spend).compress().as_bytes());
+            pubkey.extend(EdwardsPoint::mul_base(&view).compress().as_bytes());
+            pubkey
+        }
+        Algorithm::Secp256r1 => {
+            let signing_key = p256::ecdsa::SigningKey::from_slice(&G_PRIVKEY_BUF).unwrap();
+            let point = signing_key.verifying_key().to_encoded_point(false);
+            point.as_bytes()[1..].to_vec()
+        }
+    }
+}
+
+fn sign(algorithm: Algorithm, message: &[u8; 32]) -> Vec<u8> {
+    let signature = match algorithm {
+        Algorithm::Litecoin => {
+            let privkey = Privkey::from(H256::from(G_PRIVKEY_BUF));
+            let signature = privkey
+                .sign_recoverable(&H256::from(litecoin_message(message)))
+                .unwrap()
+                .serialize();
+            // 27 + 4 for a compressed pubkey, plus the recovery id
+            let header = 27 + 4 + signature[64];
+            // the pubkey is recovered from the signature
+            return [&[header][..], &signature[..64]].concat();
+        }
+        Algorithm::Cardano => {
+            use ed25519_dalek::Signer;
+            let signing_key = ed25519_dalek::SigningKey::from_bytes(&G_PRIVKEY_BUF);
+            let body = cardano_tx_body(message);
+            let mut body_hash = [0u8; 32];
+            let mut hasher = Blake2bBuilder::new(32).build();
+            hasher.update(&body);
+            hasher.finalize(&mut body_hash);
+            let signature = signing_key.sign(&body_hash).to_bytes();
+
+            let mut tx = cbor_head(4, 4);
+            tx.extend(body);
+            tx.extend([0xa1, 0x00, 0x81, 0x82]);
+            tx.extend(cbor_bytes(&pubkey(algorithm)));
+            tx.extend(cbor_bytes(&signature));
+            // valid, no auxiliary data
+            tx.extend([0xf5, 0xf6]);
+            return tx;
+        }
+        // The Monero message signature: c = Hs(prefix hash | pubkey | k * G)
+        // and r = k - c * key, with k derived from the key and message.
+        Algorithm::Monero => {
+            let (spend, _) = monero_keys();
+            let pubkey = pubkey(algorithm);
+            let prefix_hash = keccak256(message);
+            let k = Scalar::from_bytes_mod_order(keccak256(
+                &[&spend.as_bytes()[..], &prefix_hash[..]].concat(),
+            ));
+            let commitment = EdwardsPoint::mul_base(&k).compress();
+            let c = Scalar::from_bytes_mod_order(keccak256(
+                &[&prefix_hash[..], &pubkey[1..33], &commitment.as_bytes()[..]].concat(),
+            ));
+            let r = k - c * spend;
+            return [&c.to_bytes()[..], &r.to_bytes()[..], &pubkey[..]].concat();
+        }
+        Algorithm::Solana => {
+            use ed25519_dalek::Signer;
+            let signing_key = ed25519_dalek::SigningKey::from_bytes(&G_PRIVKEY_BUF);
+            signing_key.sign(message).to_bytes().to_vec()
+        }
+        Algorithm::Ripple => {
+            use k256::ecdsa::signature::hazmat::PrehashSigner;
+            let signing_key = k256::ecdsa::SigningKey::from_slice(&G_PRIVKEY_BUF).unwrap();
+            let pubkey = pubkey(algorithm);
+            let unsigned = [&b"STX\0"[..], &ripple_tx(message, &pubkey, None)[..]].concat();
+            let digest = &Sha512::digest(unsigned)[..32];
+            let signature: k256::ecdsa::Signature = signing_key.sign_prehash(digest).unwrap();
+            let signature = signature.to_der();
+            return ripple_tx(message, &pubkey, Some(signature.as_bytes()));
+        }
+        Algorithm::Secp256r1 => {
+            use p256::ecdsa::signature::Signer;
+            let signing_key = p256::ecdsa::SigningKey::from_slice(&G_PRIVKEY_BUF).unwrap();
+            let signature: p256::ecdsa::Signature = signing_key.sign(message);
+            signature.to_bytes().to_vec()
+        }
+    };
+    [pubkey(algorithm), signature].concat()
+}
+
+fn set_witness(tx: &mut ReprMockTransaction, lock: Vec<u8>) {
+    tx.tx.witnesses.clear();
+    tx.tx.witnesses.push(JsonBytes::from_bytes(
+        WitnessArgsBuilder::default()
+            .lock(Some(Bytes::from(lock)).pack())
+            .build()
+            .as_bytes(),
+    ));
+}
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let algorithm = Args::parse().algorithm;
+    let mut tx = read_tx_template("../ckb-debugger-tests/templates/child-script-success.json")?;
+
+    let mut args = vec![algorithm as u8];
+    args.extend(pubkey_hash(algorithm));
+    tx.mock_info.inputs[0].output.lock.args = JsonBytes::from_vec(args);
+
+    // The placeholder must be as long as the signature. A DER encoded
+    // signature varies in length, so sign again until they are the same.
+    let mut len = sign(algorithm, &[0u8; 32]).len();
+    loop {
+        set_witness(&mut tx, vec![0u8; len]);
+        let message = generate_sighash_all(&tx, 0)?;
+        let signature = sign(algorithm, &message);
+        if signature.len() == len {
+            set_witness(&mut tx, signature);
+            break;
+        }
+        len = signature.len();
+    }
+
+    let json = serde_json::to_string_pretty(&tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,105 @@
+// child-script-example signing a building packet, in version 4 args. The
+// signing message is checked against fixed vectors first, which are computed
+// independently of ckb-combine-lock-types.
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{
+    create_action, create_auth_args_v4, create_message, create_script_from_cell_dep,
+    create_sighash_all, generate_sighash_all, generate_signing_message, hash::blake160,
+    hash_signing_message, read_tx_template, ENTRY_CATEGORY_DYNAMIC_LINKING,
+};
+use ckb_jsonrpc_types::JsonBytes;
+use ckb_mock_tx_types::ReprMockTransaction;
+use ckb_types::{bytes::Bytes, packed, packed::WitnessArgsBuilder, prelude::*, H256};
+
+static G_PRIVKEY_BUF: [u8; 32] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+];
+
+// (actions, Message in molecule, signing message with a sighash of 0x33 repeated)
+static VECTORS: [(&[([u8; 32], [u8; 32], &[u8])], &str, &str); 2] = [
+    (
+        &[],
+        "0c0000000800000004000000",
+        "2d9793ac232dbd9eb0fe7b78ef634a67eed7afcbf9b4d38ebff9cafb72f0946c",
+    ),
+    (
+        &[([0x11; 32], [0x22; 32], b"transfer")],
+        concat!(
+            "6c0000000800000064000000080000005c000000100000003000000050000000",
+            "1111111111111111111111111111111111111111111111111111111111111111",
+            "2222222222222222222222222222222222222222222222222222222222222222",
+            "080000007472616e73666572",
+        ),
+        "36f4af1b92e76106c7a150ff1eb8db745de77bef6310d66b78fef22fb3b71fa8",
+    ),
+];
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn check_vectors() {
+    for (actions, expected_message, expected_hash) in VECTORS.iter() {
+        let actions = actions
+            .iter()
+            .map(|(info_hash, script_hash, data)| create_action(info_hash, script_hash, data))
+            .collect();
+        let message = create_message(actions);
+        assert_eq!(to_hex(message.as_slice()), *expected_message);
+        let hash = hash_signing_message(&message, &[0x33; 32]);
+        assert_eq!(to_hex(&hash), *expected_hash);
+    }
+}
+
+fn set_witness(tx: &mut ReprMockTransaction, lock: &[u8]) {
+    tx.tx.witnesses[0] = JsonBytes::from_bytes(
+        WitnessArgsBuilder::default()
+            .lock(Some(Bytes::from(lock.to_vec())).pack())
+            .build()
+            .as_bytes(),
+    );
+}
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    check_vectors();
+    let args: Vec<String> = std::env::args().collect();
+
+    let private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let pubkey_hash = blake160(&private_key.pubkey().expect("pubkey").serialize());
+    let mut tx = read_tx_template("../ckb-debugger-tests/templates/child-script-success.json")?;
+    let entry = create_script_from_cell_dep(&tx, 1, false)?;
+    let auth_args = create_auth_args_v4(0, &pubkey_hash, &entry, ENTRY_CATEGORY_DYNAMIC_LINKING);
+    tx.mock_info.inputs[0].output.lock.args = JsonBytes::from_vec(auth_args);
+
+    let lock_hash: [u8; 32] = packed::Script::from(tx.mock_info.inputs[0].output.lock.clone())
+        .calc_script_hash()
+        .unpack();
+    let actions = match args[1].as_str() {
+        "single-action" => vec![create_action(&[0x11; 32], &lock_hash, b"transfer")],
+        "multiple-actions" => vec![
+            create_action(&[0x11; 32], &lock_hash, b"transfer"),
+            create_action(&[0x12; 32], &lock_hash, &[]),
+        ],
+        "no-action" => vec![],
+        _ => unreachable!(),
+    };
+    let message = create_message(actions);
+
+    // the whole SighashAll is zeroed, the seal is a 65 bytes signature
+    set_witness(
+        &mut tx,
+        &vec![0u8; create_sighash_all(&message, &[0u8; 65]).total_size()],
+    );
+    let sighash = generate_sighash_all(&tx, 0)?;
+    let signing_message = generate_signing_message(&tx, &message, &sighash)?;
+    let sig = private_key
+        .sign_recoverable(&H256::from(signing_message))
+        .expect("sign")
+        .serialize();
+    set_witness(&mut tx, create_sighash_all(&message, &sig).as_slice());
+
+    let json = serde_json::to_string_pretty(&tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
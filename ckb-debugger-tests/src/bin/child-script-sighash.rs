@@ -0,0 +1,100 @@
+// child-script-example signing with sighash modes, in version 3 args. Inputs
+// and outputs which are not signed are added after signing.
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{
+    create_auth_args_v3, create_script_from_cell_dep, create_sighash_output_range,
+    generate_sighash, hash::blake160, read_tx_template, ENTRY_CATEGORY_DYNAMIC_LINKING,
+    SIGHASH_ALL, SIGHASH_ANYONECANPAY, SIGHASH_NONE, SIGHASH_SINGLE, SIGHASH_WITNESS_DIGEST,
+};
+use ckb_jsonrpc_types::JsonBytes;
+use ckb_mock_tx_types::ReprMockTransaction;
+use ckb_types::{bytes::Bytes, packed::WitnessArgsBuilder, prelude::*, H256};
+
+static G_PRIVKEY_BUF: [u8; 32] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+];
+
+// Witnesses longer than a chunk (32 KB) are loaded in several chunks.
+const LONG_WITNESS_LEN: usize = 40 * 1024;
+
+fn set_witness(tx: &mut ReprMockTransaction, lock: Vec<u8>, long_witness: bool) {
+    let mut builder = WitnessArgsBuilder::default().lock(Some(Bytes::from(lock)).pack());
+    if long_witness {
+        builder = builder.input_type(Some(Bytes::from(vec![0x11; LONG_WITNESS_LEN])).pack());
+    }
+    tx.tx.witnesses[0] = JsonBytes::from_bytes(builder.build().as_bytes());
+}
+
+// A witness not covered by inputs.
+fn add_uncovered_witness(tx: &mut ReprMockTransaction) {
+    let witness = vec![0x22; LONG_WITNESS_LEN];
+    tx.tx.witnesses.push(JsonBytes::from_vec(witness));
+}
+
+// An input locked by another script, e.g. from a buyer.
+fn add_input(tx: &mut ReprMockTransaction) {
+    let mut input = tx.mock_info.inputs[0].clone();
+    input.input.previous_output.index = (tx.mock_info.inputs.len() as u32).into();
+    input.output.lock.args = JsonBytes::from_vec(vec![0; 21]);
+    tx.tx.inputs.push(input.input.clone());
+    tx.mock_info.inputs.push(input);
+}
+
+fn add_output(tx: &mut ReprMockTransaction) {
+    let mut output = tx.tx.outputs[0].clone();
+    output.capacity = 0x1000u64.into();
+    tx.tx.outputs.push(output);
+    tx.tx.outputs_data.push(JsonBytes::default());
+}
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    // sighash mode, adding an input after signing, adding an output after signing
+    let (mut mode, more_input, more_output) = match args[1].as_str() {
+        "all" | "all-digest" => (vec![SIGHASH_ALL], false, false),
+        "none" => (vec![SIGHASH_NONE], false, true),
+        "single" | "single-digest" => (vec![SIGHASH_SINGLE], false, true),
+        "single-anyone-can-pay" => (vec![SIGHASH_SINGLE | SIGHASH_ANYONECANPAY], true, true),
+        "all-anyone-can-pay" => (vec![SIGHASH_ALL | SIGHASH_ANYONECANPAY], true, false),
+        "output-range" => (create_sighash_output_range(false, 0, 1), false, true),
+        _ => unreachable!(),
+    };
+    // The digests are computed by the script itself without ckb-combine-lock,
+    // with witnesses longer than a chunk.
+    let witness_digest = args[1].ends_with("-digest");
+    if witness_digest {
+        mode[0] |= SIGHASH_WITNESS_DIGEST;
+    }
+    let private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let pubkey_hash = blake160(&private_key.pubkey().expect("pubkey").serialize());
+
+    let mut tx = read_tx_template("../ckb-debugger-tests/templates/child-script-success.json")?;
+    let entry = create_script_from_cell_dep(&tx, 1, false)?;
+    let auth_args = create_auth_args_v3(0, &pubkey_hash, &entry, ENTRY_CATEGORY_DYNAMIC_LINKING);
+    tx.mock_info.inputs[0].output.lock.args = JsonBytes::from_vec(auth_args);
+
+    if witness_digest {
+        add_uncovered_witness(&mut tx);
+    }
+
+    // the sighash mode is zeroed together with the signature
+    set_witness(&mut tx, vec![0u8; mode.len() + 65], witness_digest);
+    let message = generate_sighash(&tx, 0, &mode)?;
+    let sig = private_key
+        .sign_recoverable(&H256::from(message))
+        .expect("sign")
+        .serialize();
+    set_witness(&mut tx, [mode, sig].concat(), witness_digest);
+
+    if more_input {
+        add_input(&mut tx);
+    }
+    if more_output {
+        add_output(&mut tx);
+    }
+
+    let json = serde_json::to_string_pretty(&tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
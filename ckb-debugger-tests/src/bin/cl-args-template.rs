@@ -0,0 +1,60 @@
+// The same ChildScriptConfig is shared by users with different pubkey hashes:
+// `args` of the child script is the algorithm id only, and the pubkey hash
+// comes from user args in combine lock script args.
+use ckb_combine_lock_types::combine_lock::ChildScriptConfig;
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{
+    create_args_slice_vec, create_args_v1, create_child_script_config_v2, create_threshold_vec,
+    create_witness_args_v2, generate_sighash_all,
+    hash::{blake160, hash},
+    read_tx_template, ARGS_FLAG_USER_ARGS, CONFIG_SOURCE_WITNESS,
+};
+use ckb_types::prelude::Pack;
+use ckb_types::H256;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+const G_PRIVKEY_BUF: [u8; 32] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+];
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-child-script.json")?;
+
+    let child_script_private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let child_script_pubkey = child_script_private_key.pubkey().expect("pubkey");
+    let child_script_pubkey_hash = blake160(&child_script_pubkey.serialize());
+
+    // algorithm id 0: CKB
+    let child_script_config =
+        create_child_script_config_v2(&repr_tx, &[1], &[vec![0u8].into()], &[&[0]], false)?
+            .threshold(create_threshold_vec(&[0]))
+            .args_slice(create_args_slice_vec(&[(0, 20)]))
+            .build();
+
+    // user args: pubkey hash
+    let mut fields = vec![];
+    fields.extend(hash(child_script_config.as_slice()));
+    fields.extend(child_script_pubkey_hash);
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(
+        create_args_v1(CONFIG_SOURCE_WITNESS | ARGS_FLAG_USER_ARGS, &fields),
+    );
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args =
+        create_witness_args_v2(&child_script_config, 0, &[vec![0u8; 65].into()], &[])?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let message = generate_sighash_all(&repr_tx, 0)?;
+    let sig = child_script_private_key
+        .sign_recoverable(&H256::from(message))
+        .expect("sign")
+        .serialize();
+    let witness_args = create_witness_args_v2(&child_script_config, 0, &[Bytes::from(sig)], &[])?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// The same child script config with legacy args and versioned args.
+use ckb_debugger_tests::{
+    create_args_v1, create_child_script_config, create_witness_args, hash::hash, read_tx_template,
+    ARGS_FLAG_USER_ARGS, CONFIG_SOURCE_WITNESS,
+};
+use ckb_types::prelude::Pack;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?;
+
+    let config_hash = hash(child_script_config.as_slice());
+    let lock_args = match args[1].as_str() {
+        "legacy" => config_hash.to_vec(),
+        // the legacy layout with a leading flag byte 0, see args.rs
+        "legacy-flag" => [&[0x00][..], &config_hash[..]].concat(),
+        "v1" => create_args_v1(CONFIG_SOURCE_WITNESS, &config_hash),
+        // user args not referred by any child script
+        "v1-user-args" => create_args_v1(
+            CONFIG_SOURCE_WITNESS | ARGS_FLAG_USER_ARGS,
+            &[&config_hash[..], &[1, 2, 3]].concat(),
+        ),
+        _ => unreachable!(),
+    };
+    repr_tx.mock_info.inputs[0].output.lock.args =
+        ckb_jsonrpc_types::JsonBytes::from_vec(lock_args);
+
+    let witness_args =
+        create_witness_args(&child_script_config, 0, &[(); 1].map(|_| Bytes::default()))?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
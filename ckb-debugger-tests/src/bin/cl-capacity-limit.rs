@@ -0,0 +1,67 @@
+use ckb_combine_lock_types::combine_lock::ChildScriptConfig;
+use ckb_debugger_tests::{
+    create_capacity_limit_vec, create_child_script_config_v2, create_threshold_vec,
+    create_witness_args_v2, hash::hash, read_tx_template,
+};
+use ckb_types::prelude::Pack;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    // (limit in config, fee limit in config, whether the output is sent back
+    // to the same lock, fee)
+    let (limit, fee_limit, same_lock, fee): (u64, &[u64], bool, u64) = match args[1].as_str() {
+        "same-lock" => (0, &[], true, 0),
+        // all capacity goes back to the same lock except the fee
+        "same-lock-with-fee" => (0, &[1000], true, 1000),
+        "within-limit" => (1000, &[], false, 0),
+        "no-limit" => (u64::MAX, &[], false, 0),
+        _ => unreachable!(),
+    };
+
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0]))
+    .output_capacity_limit(create_capacity_limit_vec(&[limit]))
+    .fee_limit(create_capacity_limit_vec(fee_limit))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+    let input_capacity = u64::from(repr_tx.mock_info.inputs[0].output.capacity);
+    let lock = repr_tx.mock_info.inputs[0].output.lock.clone();
+    if same_lock {
+        repr_tx.tx.outputs[0].capacity = (input_capacity - fee).into();
+        repr_tx.tx.outputs[0].lock = lock;
+    } else {
+        // 1000 shannons to another lock, and the change back to the same lock
+        repr_tx.tx.outputs[0].capacity = 1000u64.into();
+        let mut change = repr_tx.tx.outputs[0].clone();
+        change.capacity = (input_capacity - 1000).into();
+        change.lock = lock;
+        repr_tx.tx.outputs.push(change);
+        repr_tx.tx.outputs_data.push(Default::default());
+    }
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+        &[],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
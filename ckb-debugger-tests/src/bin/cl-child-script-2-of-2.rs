@@ -0,0 +1,62 @@
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{
+    create_args_v1, create_child_script_config, create_witness_args, generate_sighash_all,
+    hash::{blake160, hash},
+    read_tx_template, CONFIG_SOURCE_WITNESS,
+};
+use ckb_types::prelude::Pack;
+use ckb_types::H256;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+const G_PRIVKEY_BUF: [[u8; 32]; 2] = [[0x01; 32], [0x02; 32]];
+
+// Two child-script-example sign the same message, which is computed by combine
+// lock with both inner witnesses zeroed and passed to them as context.
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-child-script.json")?;
+
+    let private_keys = G_PRIVKEY_BUF.map(|key| Privkey::from(H256::from(key)));
+    let auth: Vec<Bytes> = private_keys
+        .iter()
+        .map(|key| {
+            let pubkey = key.pubkey().expect("pubkey");
+            let mut auth = vec![0u8; 21];
+            auth[0] = 0; // CKB
+            auth[1..].copy_from_slice(&blake160(&pubkey.serialize()));
+            Bytes::from(auth)
+        })
+        .collect();
+
+    let child_script_config =
+        create_child_script_config(&repr_tx, &[1, 1], &auth, &[&[0, 1]], false)?;
+
+    // args version 1, so the child scripts get ParentContext
+    let args = create_args_v1(CONFIG_SOURCE_WITNESS, &hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let witness_args = create_witness_args(
+        &child_script_config,
+        0,
+        &[(); 2].map(|_| Bytes::from(vec![0u8; 65])),
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let message = generate_sighash_all(&repr_tx, 0)?;
+    let sigs: Vec<Bytes> = private_keys
+        .iter()
+        .map(|key| {
+            let sig = key
+                .sign_recoverable(&H256::from(message))
+                .expect("sign")
+                .serialize();
+            Bytes::from(sig)
+        })
+        .collect();
+    let witness_args = create_witness_args(&child_script_config, 0, &sigs)?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// ChildScriptConfig is in the data of a cell_dep, located by its type hash.
+use ckb_debugger_tests::{
+    create_args_v1, create_child_script_config, create_witness_args_without_config, hash::hash,
+    read_tx_template, CONFIG_SOURCE_CELL_DEP,
+};
+use ckb_types::packed;
+use ckb_types::prelude::Pack;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-config-cell-dep.json")?;
+
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?;
+    repr_tx.mock_info.cell_deps[2].data =
+        ckb_jsonrpc_types::JsonBytes::from_vec(child_script_config.as_slice().to_vec());
+    let config_cell_type: packed::Script = repr_tx.mock_info.cell_deps[2]
+        .output
+        .type_
+        .clone()
+        .unwrap()
+        .into();
+
+    let mut fields = vec![];
+    fields.extend(hash(config_cell_type.as_slice()));
+    fields.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args =
+        ckb_jsonrpc_types::JsonBytes::from_vec(create_args_v1(CONFIG_SOURCE_CELL_DEP, &fields));
+
+    let witness_args = create_witness_args_without_config(0, &[(); 1].map(|_| Bytes::default()))?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
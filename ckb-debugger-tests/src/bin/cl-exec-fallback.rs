@@ -0,0 +1,46 @@
+use ckb_debugger_tests::{
+    create_child_script_config, create_witness_args, hash::hash, read_tx_template,
+};
+use ckb_types::prelude::Pack;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+// exec: the combine lock is built with "exec-fallback" feature, the only child
+//     script is run by exec.
+// dl-exec: the same combine lock with 2 child scripts, the first one is run by
+//     dynamic linking and the last one by exec.
+// spawn: the same 2 child scripts run by spawn with the default combine lock,
+//     for comparing the cycles with dl-exec.
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let (template, cell_dep_index): (_, &[usize]) = match args[1].as_str() {
+        "exec" => ("cl-exec-fallback.json", &[1]),
+        // child-script-dl-always-success, then child-script-always-success
+        "dl-exec" => ("cl-exec-fallback.json", &[2, 1]),
+        "spawn" => ("cl-always-success.json", &[1, 1]),
+        _ => unreachable!(),
+    };
+    let mut repr_tx = read_tx_template(&format!("../ckb-debugger-tests/templates/{}", template))?;
+
+    let count = cell_dep_index.len();
+    let child_scripts: Vec<u8> = (0..count as u8).collect();
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        cell_dep_index,
+        &vec![Bytes::default(); count],
+        &[&child_scripts],
+        false,
+    )?;
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let witness_args =
+        create_witness_args(&child_script_config, 0, &vec![Bytes::default(); count])?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
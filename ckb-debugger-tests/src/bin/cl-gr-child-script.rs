@@ -0,0 +1,123 @@
+// Same as gr-child-script, but the global registry is handled by combine lock
+// natively, without lock-wrapper.
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{
+    create_args_v1, create_child_script_config, create_witness_args,
+    create_witness_args_without_config, generate_sighash_all,
+    hash::{blake160, hash},
+    read_tx_template, CONFIG_SOURCE_GLOBAL_REGISTRY,
+};
+use ckb_jsonrpc_types::JsonBytes;
+use ckb_types::packed::{Script, WitnessArgs};
+use ckb_types::prelude::Pack;
+use ckb_types::H256;
+use clap::Parser;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+const G_PRIVKEY_BUF: [u8; 32] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+];
+
+#[derive(Parser)]
+struct Args {
+    #[arg(long)]
+    has_config_cell: bool,
+    // the proof in config cell doesn't cover the child script config hash
+    #[arg(long)]
+    out_of_range: bool,
+    // the legacy layout | 1 byte flag = 1 | ID | config hash |, see args.rs
+    #[arg(long)]
+    legacy_args: bool,
+}
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    drop(env_logger::init());
+    let clap_args = Args::parse();
+
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-gr-child-script.json")?;
+
+    let child_script_private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let child_script_pubkey = child_script_private_key.pubkey().expect("pubkey");
+    let child_script_pubkey_hash = blake160(&child_script_pubkey.serialize());
+    let mut auth = vec![0u8; 21];
+    auth[0] = 0; // CKB
+    auth[1..].copy_from_slice(&child_script_pubkey_hash);
+
+    let child_script_config =
+        create_child_script_config(&repr_tx, &[1], &[auth.into()], &[&[0]], false)?;
+    let child_script_config_hash = hash(child_script_config.as_slice());
+    let global_registry_id = {
+        let type_ = repr_tx
+            .mock_info
+            .cell_deps
+            .last()
+            .unwrap()
+            .output
+            .type_
+            .as_ref()
+            .unwrap();
+        let type_: Script = type_.clone().into();
+        hash(type_.as_slice())
+    };
+    let mut fields = global_registry_id.to_vec();
+    fields.extend(child_script_config_hash);
+    let mut args = if clap_args.legacy_args {
+        [&[1][..], &fields[..]].concat()
+    } else {
+        create_args_v1(CONFIG_SOURCE_GLOBAL_REGISTRY, &fields)
+    };
+    repr_tx.mock_info.inputs[0].output.lock.args = JsonBytes::from_vec(args.clone());
+
+    if !clap_args.has_config_cell {
+        // this cell_dep is a proof that this child script config doesn't exist
+        // in config cell
+        let l = args.len();
+        for i in l - 8..l {
+            args[i] = 0;
+        }
+    }
+    // config cell shares the same lock script code, the last cell_dep is the
+    // config cell.
+    repr_tx
+        .mock_info
+        .cell_deps
+        .last_mut()
+        .unwrap()
+        .output
+        .lock
+        .args = JsonBytes::from_vec(args.clone());
+
+    // next hash is set to 0xFF..FF, maximum one
+    let mut input_data = if clap_args.out_of_range {
+        args[args.len() - 32..].to_vec()
+    } else {
+        vec![0xFF; 32]
+    };
+    if clap_args.has_config_cell {
+        input_data.extend(child_script_config.as_slice());
+    }
+    repr_tx.mock_info.cell_deps.last_mut().unwrap().data = JsonBytes::from_vec(input_data);
+
+    let witness_args = |inner_witness: &[Bytes]| -> Result<WitnessArgs, anyhow::Error> {
+        if clap_args.has_config_cell {
+            create_witness_args_without_config(0, inner_witness)
+        } else {
+            create_witness_args(&child_script_config, 0, inner_witness)
+        }
+    };
+    repr_tx.tx.witnesses[0] =
+        JsonBytes::from(witness_args(&[vec![0u8; 65].into()])?.as_bytes().pack());
+
+    let message = generate_sighash_all(&repr_tx, 0)?;
+    let sig = child_script_private_key
+        .sign_recoverable(&H256::from(message))
+        .expect("sign")
+        .serialize();
+    repr_tx.tx.witnesses[0] = JsonBytes::from(witness_args(&[sig.into()])?.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
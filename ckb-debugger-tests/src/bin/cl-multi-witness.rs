@@ -0,0 +1,70 @@
+// Three inputs in the same script group with mixed unlock paths:
+// input 0: signed by a key, its witness also unlocks input 2
+// input 1: timelock recovery, with its own witness
+// input 2: no witness
+use ckb_combine_lock_types::combine_lock::ChildScriptConfig;
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{
+    create_child_script_config_v2, create_since_vec, create_threshold_vec, create_witness_args_v2,
+    generate_sighash_all,
+    hash::{blake160, hash},
+    read_tx_template,
+};
+use ckb_types::prelude::Pack;
+use ckb_types::H256;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+const G_PRIVKEY_BUF: [u8; 32] = [0x01; 32];
+// relative block number
+const REQUIRED_SINCE: u64 = 0x8000_0000_0000_0000 | 10;
+const INPUT_SINCE: u64 = 0x8000_0000_0000_0000 | 20;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-multi-witness.json")?;
+    repr_tx.mock_info.inputs[1].input.since = INPUT_SINCE.into();
+    repr_tx.tx.inputs[1].since = INPUT_SINCE.into();
+
+    let private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let pubkey = private_key.pubkey().expect("pubkey");
+    let mut auth = vec![0u8; 21];
+    auth[0] = 0; // CKB
+    auth[1..].copy_from_slice(&blake160(&pubkey.serialize()));
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1, 4],
+        &[Bytes::from(auth), Bytes::default()],
+        &[&[0], &[1]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0, 0]))
+    .since(create_since_vec(&[0, REQUIRED_SINCE]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    for input in repr_tx.mock_info.inputs.iter_mut() {
+        input.output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args.clone());
+    }
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(&child_script_config, 1, &[Bytes::default()], &[])?;
+    repr_tx.tx.witnesses[1] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let witness_args =
+        create_witness_args_v2(&child_script_config, 0, &[Bytes::from(vec![0u8; 65])], &[])?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let message = generate_sighash_all(&repr_tx, 0)?;
+    let sig = private_key
+        .sign_recoverable(&H256::from(message))
+        .expect("sign")
+        .serialize();
+    let witness_args = create_witness_args_v2(&child_script_config, 0, &[Bytes::from(sig)], &[])?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use ckb_combine_lock_types::combine_lock::ChildScriptConfig;
+use ckb_debugger_tests::{
+    create_child_script_config_v2, create_policy, create_witness_args_v2, hash::hash,
+    read_tx_template,
+};
+use ckb_types::prelude::Pack;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-policy.json")?;
+
+    // (A AND B) OR (2 of C, D, E)
+    // A and D are always_failure, the others are always_success.
+    // With "threshold-all", the threshold node is all of C and E, by 0.
+    let threshold_node: (u8, u8, &[u8]) = match args.get(1).map(String::as_str) {
+        Some("threshold-all") => (3, 0, &[5, 7]),
+        _ => (3, 2, &[5, 6, 7]),
+    };
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1, 2, 1],
+        &[(); 3].map(|_| Bytes::default()),
+        &[],
+        false,
+    )?
+    .policy(create_policy(&[
+        (2, 0, &[1, 2]),
+        (1, 0, &[3, 4]),
+        threshold_node,
+        (0, 0, &[1]),
+        (0, 0, &[0]),
+        (0, 0, &[0]),
+        (0, 0, &[1]),
+        (0, 0, &[2]),
+    ]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    // C and E are chosen
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(
+        &child_script_config,
+        0,
+        &[(); 2].map(|_| Bytes::default()),
+        &[5, 7],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use ckb_combine_lock_types::combine_lock::ChildScriptConfig;
+use ckb_debugger_tests::{
+    create_child_script_config_v2, create_cycles_limit_vec, create_memory_limit_vec,
+    create_threshold_vec, create_witness_args_v2, hash::hash, read_tx_template,
+};
+use ckb_types::prelude::Pack;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    // (memory limit, cycles limit) of the only child script
+    let (memory_limit, cycles_limit): (&[u8], &[u64]) = match args[1].as_str() {
+        "default" => (&[], &[]),
+        "minimum-memory" => (&[1], &[]),
+        "within-cycles-limit" => (&[0], &[1_000_000]),
+        _ => unreachable!(),
+    };
+
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0]))
+    .memory_limit(create_memory_limit_vec(memory_limit))
+    .cycles_limit(create_cycles_limit_vec(cycles_limit))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+        &[],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Two inputs in the same script group, each with its own witness and child
+// script. Both child scripts sign the message shared by combine lock, with the
+// signatures in both witnesses zeroed.
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{
+    create_args_v1, create_child_script_config, create_witness_args,
+    generate_sighash_all_with_masks,
+    hash::{blake160, hash},
+    inner_witness_masks, read_tx_template, CONFIG_SOURCE_WITNESS,
+};
+use ckb_types::prelude::Pack;
+use ckb_types::H256;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+const G_PRIVKEY_BUF: [[u8; 32]; 2] = [[0x01; 32], [0x02; 32]];
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-multi-witness.json")?;
+
+    let private_keys = G_PRIVKEY_BUF.map(|key| Privkey::from(H256::from(key)));
+    let auth: Vec<Bytes> = private_keys
+        .iter()
+        .map(|key| {
+            let pubkey = key.pubkey().expect("pubkey");
+            let mut auth = vec![0u8; 21];
+            auth[0] = 0; // CKB
+            auth[1..].copy_from_slice(&blake160(&pubkey.serialize()));
+            Bytes::from(auth)
+        })
+        .collect();
+
+    let child_script_config =
+        create_child_script_config(&repr_tx, &[1, 1], &auth, &[&[0], &[1]], false)?;
+
+    // args version 1, so the child scripts get ParentContext
+    let args = create_args_v1(CONFIG_SOURCE_WITNESS, &hash(child_script_config.as_slice()));
+    for input in repr_tx.mock_info.inputs.iter_mut() {
+        input.output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args.clone());
+    }
+
+    // The placeholders are not zeros, they're zeroed by the masks.
+    let mut masks = vec![];
+    for index in 0..2 {
+        let witness_args =
+            create_witness_args(&child_script_config, index as u16, &[vec![0xff; 65].into()])?;
+        for mask in inner_witness_masks(witness_args.as_slice())? {
+            masks.push((index, mask));
+        }
+        repr_tx.tx.witnesses[index] =
+            ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+    }
+
+    let message = generate_sighash_all_with_masks(&repr_tx, 0, &masks)?;
+    for (index, key) in private_keys.iter().enumerate() {
+        let sig = key
+            .sign_recoverable(&H256::from(message))
+            .expect("sign")
+            .serialize();
+        let witness_args =
+            create_witness_args(&child_script_config, index as u16, &[Bytes::from(sig)])?;
+        repr_tx.tx.witnesses[index] =
+            ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+    }
+    // The signatures don't change the message.
+    assert_eq!(
+        message,
+        generate_sighash_all_with_masks(&repr_tx, 0, &masks)?
+    );
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,121 @@
+// Child scripts of combine lock signing with sighash modes, with or without
+// the witness digests passed by combine lock, in the transactions of
+// child-script-long-witness and child-script-multi-inputs. It's for comparing
+// the cycles.
+// multi-witness: the inputs of multi-inputs, input 0 unlocked by witness 0,
+// inputs 1 and 2 by witness 1. The message is the one computed by ckb-debugger-tests for the whole
+// lock group, the same as the one of child-script-sighash without combine
+// lock, so the child scripts must sign the whole group with ParentContext too.
+use ckb_crypto::secp::Privkey;
+use ckb_debugger_tests::{
+    create_args_v1, create_auth_args_v3, create_child_script_config, create_script_from_cell_dep,
+    create_witness_args_with_inner_witness_index, generate_sighash,
+    hash::{blake160, hash},
+    read_tx_template, CONFIG_SOURCE_WITNESS, ENTRY_CATEGORY_DYNAMIC_LINKING, SIGHASH_ALL,
+    SIGHASH_ANYONECANPAY, SIGHASH_WITNESS_DIGEST,
+};
+use ckb_jsonrpc_types::JsonBytes;
+use ckb_mock_tx_types::ReprMockTransaction;
+use ckb_types::packed::WitnessArgs;
+use ckb_types::prelude::Pack;
+use ckb_types::H256;
+use molecule::bytes::Bytes;
+use molecule::prelude::{Builder, Entity};
+
+const G_PRIVKEY_BUF: [u8; 32] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+];
+
+const CHILD_SCRIPT_COUNT: usize = 3;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let (long_witness, multi_witness) = match args[1].as_str() {
+        "long-witness" => (true, false),
+        "multi-inputs" => (false, false),
+        "multi-witness" => (false, true),
+        _ => unreachable!(),
+    };
+    let mode = match args[2].as_str() {
+        "all" => SIGHASH_ALL,
+        "all-digest" => SIGHASH_ALL | SIGHASH_WITNESS_DIGEST,
+        "all-anyone-can-pay" => SIGHASH_ALL | SIGHASH_ANYONECANPAY,
+        "all-anyone-can-pay-digest" => SIGHASH_ALL | SIGHASH_ANYONECANPAY | SIGHASH_WITNESS_DIGEST,
+        _ => unreachable!(),
+    };
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-child-script.json")?;
+
+    let private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let pubkey_hash = blake160(&private_key.pubkey().expect("pubkey").serialize());
+    let entry = create_script_from_cell_dep(&repr_tx, 2, false)?;
+    let auth: Bytes =
+        create_auth_args_v3(0, &pubkey_hash, &entry, ENTRY_CATEGORY_DYNAMIC_LINKING).into();
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[1; CHILD_SCRIPT_COUNT],
+        &vec![auth; CHILD_SCRIPT_COUNT],
+        &[&[0, 1, 2]],
+        false,
+    )?;
+    // args version 1, so the child scripts get ParentContext
+    repr_tx.mock_info.inputs[0].output.lock.args = JsonBytes::from_vec(create_args_v1(
+        CONFIG_SOURCE_WITNESS,
+        &hash(child_script_config.as_slice()),
+    ));
+
+    if !long_witness {
+        // 3 inputs in the script group, and 3 witnesses not covered by inputs
+        for _ in 0..2 {
+            let mut input = repr_tx.mock_info.inputs[0].clone();
+            input.input.previous_output.index = (repr_tx.mock_info.inputs.len() as u32).into();
+            repr_tx.tx.inputs.push(input.input.clone());
+            repr_tx.mock_info.inputs.push(input);
+            repr_tx.tx.witnesses.push(JsonBytes::default());
+        }
+        for _ in 0..3 {
+            let witness_args = WitnessArgs::new_builder()
+                .lock(Some(Bytes::from(vec![0u8; 65])).pack())
+                .build();
+            repr_tx
+                .tx
+                .witnesses
+                .push(JsonBytes::from_bytes(witness_args.as_bytes()));
+        }
+    }
+    // The witnesses of the group inputs unlocked separately
+    let group_witnesses: &[usize] = if multi_witness { &[0, 1] } else { &[0] };
+    // All child scripts share the same inner witness.
+    let set_witness = |repr_tx: &mut ReprMockTransaction, inner: Bytes| {
+        let mut witness_args = create_witness_args_with_inner_witness_index(
+            &child_script_config,
+            0,
+            &[inner],
+            &[],
+            &[0; CHILD_SCRIPT_COUNT],
+        )?;
+        if long_witness {
+            witness_args = witness_args
+                .as_builder()
+                .input_type(Some(Bytes::from(vec![0u8; 1024 * 100])).pack())
+                .build();
+        }
+        for &i in group_witnesses {
+            repr_tx.tx.witnesses[i] = JsonBytes::from_bytes(witness_args.as_bytes());
+        }
+        Ok::<(), anyhow::Error>(())
+    };
+
+    // the sighash mode is zeroed together with the signature
+    set_witness(&mut repr_tx, vec![0u8; 1 + 65].into())?;
+    let message = generate_sighash(&repr_tx, 0, &[mode])?;
+    let sig = private_key
+        .sign_recoverable(&H256::from(message))
+        .expect("sign")
+        .serialize();
+    set_witness(&mut repr_tx, [vec![mode], sig].concat().into())?;
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use ckb_combine_lock_types::combine_lock::ChildScriptConfig;
+use ckb_debugger_tests::{
+    create_child_script_config_v2, create_since_vec, create_threshold_vec, create_witness_args_v2,
+    hash::hash, read_tx_template,
+};
+use ckb_types::prelude::Pack;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+fn epoch(number: u64, index: u64, length: u64) -> u64 {
+    number | (index << 24) | (length << 40)
+}
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    // (required since in config, since of input)
+    let (required, since) = match args[1].as_str() {
+        "absolute-block-number" => (100, 100),
+        "relative-block-number" => (0x8000_0000_0000_0000 | 10, 0x8000_0000_0000_0000 | 20),
+        "absolute-epoch" => (
+            0x2000_0000_0000_0000 | epoch(10, 1, 2),
+            0x2000_0000_0000_0000 | epoch(10, 2, 3),
+        ),
+        "relative-epoch" => (
+            0xa000_0000_0000_0000 | epoch(1, 0, 1),
+            0xa000_0000_0000_0000 | epoch(1, 1, 2),
+        ),
+        "absolute-timestamp" => (
+            0x4000_0000_0000_0000 | 1_700_000_000,
+            0x4000_0000_0000_0000 | 1_700_000_001,
+        ),
+        "relative-timestamp" => (0xc000_0000_0000_0000 | 3600, 0xc000_0000_0000_0000 | 3600),
+        _ => unreachable!(),
+    };
+
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+    repr_tx.mock_info.inputs[0].input.since = since.into();
+    repr_tx.tx.inputs[0].since = since.into();
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0]))
+    .since(create_since_vec(&[required]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+        &[],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use ckb_combine_lock_types::combine_lock::ChildScriptConfig;
+use ckb_debugger_tests::{
+    create_child_script_config_v2, create_threshold_vec, create_witness_args_v2, hash::hash,
+    read_tx_template,
+};
+use ckb_types::prelude::Pack;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-threshold.json")?;
+
+    // 2-of-3: always_success, always_failure, always_success
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1, 2, 1],
+        &[(); 3].map(|_| Bytes::default()),
+        &[&[0, 1, 2]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[2]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(
+        &child_script_config,
+        0,
+        &[(); 2].map(|_| Bytes::default()),
+        &[0, 2],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,48 @@
+// Mint a cell with combine type, gated by its child scripts.
+// mint: the only output has the type, its child script always succeeds.
+// transfer: the input also has the type and its child script always fails.
+//     It succeeds since no child script is run when the cells are only moved.
+use ckb_debugger_tests::{
+    create_child_script_config, create_witness_args_output_type, hash::hash, read_tx_template,
+};
+use ckb_types::prelude::Pack;
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let (cell_dep_index, transfer) = match args[1].as_str() {
+        "mint" => (1, false),
+        "transfer" => (2, true),
+        _ => unreachable!(),
+    };
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/ct-mint.json")?;
+
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[cell_dep_index],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?;
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    if let Some(type_) = repr_tx.tx.outputs[0].type_.as_mut() {
+        type_.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+    }
+    if transfer {
+        repr_tx.mock_info.inputs[0].output.type_ = repr_tx.tx.outputs[0].type_.clone();
+    }
+
+    let witness_args = create_witness_args_output_type(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
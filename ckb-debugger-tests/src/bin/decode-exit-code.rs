@@ -0,0 +1,21 @@
+use ckb_debugger_tests::exit_code::explain_exit_code;
+use ckb_mock_tx_types::ReprMockTransaction;
+use std::fs::read_to_string;
+
+// Usage: decode-exit-code <exit code> [transaction file] [group witness index]
+// The transaction file is the JSON fed to ckb-debugger. The group witness index
+// is from the log "group witness N failed" of ckb-combine-lock, 0 by default.
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let code: i8 = args[1].parse()?;
+    let repr_tx: Option<ReprMockTransaction> = match args.get(2) {
+        Some(file_name) => Some(serde_json::from_str(&read_to_string(file_name)?)?),
+        None => None,
+    };
+    let group_index: usize = match args.get(3) {
+        Some(index) => index.parse()?,
+        None => 0,
+    };
+    println!("{}", explain_exit_code(code, repr_tx.as_ref(), group_index));
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use ckb_debugger_tests::read_tx_template;
+
+// requires `make dl-loader-test` in the root directory
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let repr_tx = read_tx_template("../ckb-debugger-tests/templates/dl-loader-test.json")?;
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
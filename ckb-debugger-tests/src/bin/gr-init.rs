@@ -1,11 +1,23 @@
-use ckb_debugger_tests::{hash::hash, read_tx_template};
+use ckb_debugger_tests::{
+    create_args_v1, hash::hash, read_tx_template, ARGS_FLAG_USER_ARGS,
+    CONFIG_SOURCE_GLOBAL_REGISTRY,
+};
 use ckb_jsonrpc_types::JsonBytes;
 use ckb_mock_tx_types::MockTransaction;
 use ckb_types::packed::Script;
+use clap::Parser;
 use molecule::prelude::Entity;
 
+#[derive(Parser)]
+struct Args {
+    // the config cell is locked by combine lock version 1 args with user args
+    #[arg(long)]
+    user_args: bool,
+}
+
 pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     drop(env_logger::init());
+    let clap_args = Args::parse();
     let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/gr-init.json")?;
     let tx: MockTransaction = repr_tx.clone().into();
 
@@ -29,6 +41,14 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
     config_cell_lock_args.extend_from_slice(&global_registry_id);
     config_cell_lock_args.extend_from_slice(&vec![0x00; 32]);
+    if clap_args.user_args {
+        // the current hash is still the zero hash, not the last 32 bytes
+        config_cell_lock_args.extend_from_slice(&[0x11; 40]);
+        config_cell_lock_args = create_args_v1(
+            CONFIG_SOURCE_GLOBAL_REGISTRY | ARGS_FLAG_USER_ARGS,
+            &config_cell_lock_args,
+        );
+    }
     repr_tx.tx.outputs[0].lock.args = JsonBytes::from_vec(config_cell_lock_args);
 
     let json = serde_json::to_string_pretty(&repr_tx).unwrap();
@@ -0,0 +1,46 @@
+// Size of CombineLockWitness when N child scripts sign with the same key, with
+// a signature per child script, or one signature shared by inner witness index.
+use ckb_debugger_tests::{
+    create_child_script_config, create_witness_args_v2,
+    create_witness_args_with_inner_witness_index, read_tx_template,
+};
+use molecule::bytes::Bytes;
+use molecule::prelude::Entity;
+
+const SIGNATURE_SIZE: usize = 65;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-child-script.json")?;
+
+    println!("child scripts | positional | shared | saved");
+    for n in [1usize, 2, 4, 8, 16] {
+        let auth: Bytes = vec![0u8; 21].into();
+        let child_script_config = create_child_script_config(
+            &repr_tx,
+            &vec![1; n],
+            &vec![auth; n],
+            &[&(0..n as u8).collect::<Vec<u8>>()],
+            false,
+        )?;
+        let signature: Bytes = vec![0u8; SIGNATURE_SIZE].into();
+        let positional =
+            create_witness_args_v2(&child_script_config, 0, &vec![signature.clone(); n], &[])?;
+        let shared = create_witness_args_with_inner_witness_index(
+            &child_script_config,
+            0,
+            &[signature],
+            &[],
+            &vec![0; n],
+        )?;
+        let positional_size = positional.as_slice().len();
+        let shared_size = shared.as_slice().len();
+        println!(
+            "{:13} | {:10} | {:6} | {}",
+            n,
+            positional_size,
+            shared_size,
+            positional_size as i64 - shared_size as i64
+        );
+    }
+    Ok(())
+}
@@ -1,13 +1,25 @@
+use ckb_combine_lock_types::combine_lock::ChildScriptConfig;
 use ckb_crypto::secp::Privkey;
 use ckb_debugger_tests::{
-    create_child_script_config, create_witness_args, generate_sighash_all,
+    create_action, create_args_slice_vec, create_args_v1, create_auth_args_v1, create_auth_args_v2,
+    create_auth_args_v3, create_auth_args_v4, create_capacity_limit_vec,
+    create_child_script_config, create_child_script_config_v2, create_cycles_limit_vec,
+    create_memory_limit_vec, create_message, create_policy, create_script_from_cell_dep,
+    create_sighash_all, create_sighash_output_range, create_since_vec, create_threshold_vec,
+    create_witness_args, create_witness_args_output_type, create_witness_args_v2,
+    create_witness_args_with_inner_witness_index, create_witness_args_without_config,
+    generate_sighash, generate_sighash_all, generate_sighash_all_with_masks,
     hash::{blake160, hash},
-    read_tx_template,
+    hash_signing_message, inner_witness_masks, read_tx_template, ARGS_FLAG_USER_ARGS,
+    CONFIG_SOURCE_CELL_DEP, CONFIG_SOURCE_WITNESS, ENTRY_CATEGORY_DYNAMIC_LINKING,
+    ENTRY_CATEGORY_SPAWN, SIGHASH_SINGLE,
 };
+use ckb_mock_tx_types::ReprMockTransaction;
+use ckb_types::packed;
 use ckb_types::prelude::{Pack, Unpack};
 use ckb_types::H256;
 use molecule::bytes::Bytes;
-use molecule::prelude::Entity;
+use molecule::prelude::{Builder, Entity};
 
 fn cl_always_failure() -> Result<(), Box<dyn std::error::Error>> {
     let mut repr_tx =
@@ -34,7 +46,45 @@ fn cl_always_failure() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn cl_child_script_config_hash_error() -> Result<(), Box<dyn std::error::Error>> {
+// 2 inputs in the same script group, the second witness runs an always
+// failure child script after an always success one.
+fn cl_multi_witness_second_failure() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx =
+        read_tx_template("../ckb-debugger-tests/templates/negative-cl-always-failure.json")?;
+    let mut input = repr_tx.mock_info.inputs[0].clone();
+    input.input.previous_output.index = 1u32.into();
+    repr_tx.tx.inputs.push(input.input.clone());
+    repr_tx.mock_info.inputs.push(input);
+    repr_tx.tx.witnesses.push(Default::default());
+
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[1, 2],
+        &[(); 2].map(|_| Bytes::default()),
+        &[&[0], &[0, 1]],
+        false,
+    )?;
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    for input in repr_tx.mock_info.inputs.iter_mut() {
+        input.output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args.clone());
+    }
+
+    let witness_args = create_witness_args(&child_script_config, 0, &[Bytes::default()])?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+    let witness_args = create_witness_args_without_config(1, &[(); 2].map(|_| Bytes::default()))?;
+    repr_tx.tx.witnesses[1] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// `args` makes the lock args from a wrong config hash.
+fn cl_child_script_config_hash_error(
+    args: &dyn Fn(&[u8]) -> Vec<u8>,
+) -> Result<(), Box<dyn std::error::Error>> {
     let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
 
     let child_script_config = create_child_script_config(
@@ -45,8 +95,7 @@ fn cl_child_script_config_hash_error() -> Result<(), Box<dyn std::error::Error>>
         false,
     )?;
 
-    let mut args = vec![0x00u8];
-    args.extend(hash(&hash(child_script_config.as_slice())));
+    let args = args(&hash(&hash(child_script_config.as_slice())));
     repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
 
     let witness_args =
@@ -58,6 +107,29 @@ fn cl_child_script_config_hash_error() -> Result<(), Box<dyn std::error::Error>>
     Ok(())
 }
 
+// The script config is neither in the witness nor anywhere else.
+fn cl_script_config_missing() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?;
+
+    let args = hash(child_script_config.as_slice()).to_vec();
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let witness_args = create_witness_args_without_config(0, &[Bytes::default()])?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
 const G_PRIVKEY_BUF: [u8; 32] = [
     0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
     0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
@@ -212,16 +284,758 @@ fn cl_witness_length_wrong() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+fn cl_threshold(selection: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-threshold.json")?;
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1, 2, 1],
+        &[(); 3].map(|_| Bytes::default()),
+        &[&[0, 1, 2]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[2]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let inner_witness: Vec<Bytes> = selection.iter().map(|_| Bytes::default()).collect();
+    let witness_args =
+        create_witness_args_v2(&child_script_config, root, &inner_witness, selection)?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+fn cl_policy(root: u16, selection: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-policy.json")?;
+
+    // (A AND B) OR (2 of C, D, E)
+    // A and D are always_failure, the others are always_success.
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1, 2, 1],
+        &[(); 3].map(|_| Bytes::default()),
+        &[],
+        false,
+    )?
+    .policy(create_policy(&[
+        (2, 0, &[1, 2]),
+        (1, 0, &[3, 4]),
+        (3, 2, &[5, 6, 7]),
+        (0, 0, &[1]),
+        (0, 0, &[0]),
+        (0, 0, &[0]),
+        (0, 0, &[1]),
+        (0, 0, &[2]),
+    ]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let inner_witness: Vec<Bytes> = selection.iter().map(|_| Bytes::default()).collect();
+    let witness_args =
+        create_witness_args_v2(&child_script_config, root, &inner_witness, selection)?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+fn cl_since(required: u64, since: u64) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+    repr_tx.mock_info.inputs[0].input.since = since.into();
+    repr_tx.tx.inputs[0].since = since.into();
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0]))
+    .since(create_since_vec(&[required]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+        &[],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+fn cl_resource_limit(
+    memory_limit: &[u8],
+    cycles_limit: &[u64],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0]))
+    .memory_limit(create_memory_limit_vec(memory_limit))
+    .cycles_limit(create_cycles_limit_vec(cycles_limit))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+        &[],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// With the combine lock built with "exec-fallback" feature: the child scripts
+// at `cell_dep_index` are run by dynamic linking, except the last one by exec.
+fn cl_exec_fallback_cycles_limit(
+    cell_dep_index: &[usize],
+    cycles_limit: &[u64],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-exec-fallback.json")?;
+
+    let count = cell_dep_index.len();
+    let child_scripts: Vec<u8> = (0..count as u8).collect();
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        cell_dep_index,
+        &vec![Bytes::default(); count],
+        &[&child_scripts],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0]))
+    .cycles_limit(create_cycles_limit_vec(cycles_limit))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args =
+        create_witness_args_v2(&child_script_config, 0, &vec![Bytes::default(); count], &[])?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+fn cl_args_slice_out_of_range() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0]))
+    .args_slice(create_args_slice_vec(&[(0, 21)]))
+    .build();
+
+    let mut fields = vec![];
+    fields.extend(hash(child_script_config.as_slice()));
+    fields.extend([0u8; 20]);
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(
+        create_args_v1(CONFIG_SOURCE_WITNESS | ARGS_FLAG_USER_ARGS, &fields),
+    );
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+        &[],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// `external` shannons are sent to another lock, `fee` shannons are left as fee
+// and the change goes back to the same lock.
+fn cl_capacity_limit_exceeded(
+    limit: u64,
+    fee_limit: &[u64],
+    external: u64,
+    fee: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0]))
+    .output_capacity_limit(create_capacity_limit_vec(&[limit]))
+    .fee_limit(create_capacity_limit_vec(fee_limit))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+    let input_capacity = u64::from(repr_tx.mock_info.inputs[0].output.capacity);
+    repr_tx.tx.outputs[0].capacity = external.into();
+    let mut change = repr_tx.tx.outputs[0].clone();
+    change.capacity = (input_capacity - external - fee).into();
+    change.lock = repr_tx.mock_info.inputs[0].output.lock.clone();
+    repr_tx.tx.outputs.push(change);
+    repr_tx.tx.outputs_data.push(Default::default());
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+        &[],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// A policy tree with an output capacity limit, which can't be applied to it.
+fn cl_policy_capacity_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[],
+        false,
+    )?
+    .policy(create_policy(&[(0, 0, &[0])]))
+    .output_capacity_limit(create_capacity_limit_vec(&[0]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_v2(&child_script_config, 0, &[Bytes::default()], &[0])?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// Input 1 has its own witness with a timelock, which is checked against its own
+// since only.
+fn cl_multi_witness(input_since: u64) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx =
+        read_tx_template("../ckb-debugger-tests/templates/cl-always-success-3i3c.json")?;
+    repr_tx.mock_info.inputs[1].input.since = input_since.into();
+    repr_tx.tx.inputs[1].since = input_since.into();
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0], &[0]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0, 0]))
+    .since(create_since_vec(&[0, 0x8000_0000_0000_0000 | 10]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    for input in repr_tx.mock_info.inputs.iter_mut() {
+        input.output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args.clone());
+    }
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    for index in 0..2 {
+        let witness_args = create_witness_args_v2(
+            &child_script_config,
+            index as u16,
+            &[(); 1].map(|_| Bytes::default()),
+            &[],
+        )?;
+        repr_tx.tx.witnesses[index] =
+            ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+    }
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// Like cl-shared-message, but the first child script signs a message with
+// only its own signature zeroed.
+fn cl_shared_message_unmasked() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-multi-witness.json")?;
+
+    let private_keys = [[0x01; 32], [0x02; 32]].map(|key| Privkey::from(H256::from(key)));
+    let auth: Vec<Bytes> = private_keys
+        .iter()
+        .map(|key| {
+            let pubkey = key.pubkey().expect("pubkey");
+            let mut auth = vec![0u8; 21];
+            auth[0] = 0; // CKB
+            auth[1..].copy_from_slice(&blake160(&pubkey.serialize()));
+            Bytes::from(auth)
+        })
+        .collect();
+
+    let child_script_config =
+        create_child_script_config(&repr_tx, &[1, 1], &auth, &[&[0], &[1]], false)?;
+
+    // args version 1, so the child scripts get ParentContext
+    let args = create_args_v1(CONFIG_SOURCE_WITNESS, &hash(child_script_config.as_slice()));
+    for input in repr_tx.mock_info.inputs.iter_mut() {
+        input.output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args.clone());
+    }
+
+    let mut masks = vec![];
+    for index in 0..2 {
+        let witness_args =
+            create_witness_args(&child_script_config, index as u16, &[vec![0xff; 65].into()])?;
+        for mask in inner_witness_masks(witness_args.as_slice())? {
+            masks.push((index, mask));
+        }
+        repr_tx.tx.witnesses[index] =
+            ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+    }
+
+    let messages = [
+        generate_sighash_all_with_masks(&repr_tx, 0, &masks[..1])?,
+        generate_sighash_all_with_masks(&repr_tx, 0, &masks)?,
+    ];
+    for (index, key) in private_keys.iter().enumerate() {
+        let sig = key
+            .sign_recoverable(&H256::from(messages[index]))
+            .expect("sign")
+            .serialize();
+        let witness_args =
+            create_witness_args(&child_script_config, index as u16, &[Bytes::from(sig)])?;
+        repr_tx.tx.witnesses[index] =
+            ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+    }
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+fn ct_mint_always_failure() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/ct-mint.json")?;
+
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[2],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?;
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    if let Some(type_) = repr_tx.tx.outputs[0].type_.as_mut() {
+        type_.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+    }
+
+    let witness_args = create_witness_args_output_type(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// The input meets the since of the config, but it's not in the script group,
+// so minting can't meet it.
+fn ct_mint_since_not_satisfied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/ct-mint.json")?;
+    repr_tx.mock_info.inputs[0].input.since = 200u64.into();
+    repr_tx.tx.inputs[0].since = 200u64.into();
+
+    let child_script_config = create_child_script_config_v2(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?
+    .threshold(create_threshold_vec(&[0]))
+    .since(create_since_vec(&[100]))
+    .build();
+
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    if let Some(type_) = repr_tx.tx.outputs[0].type_.as_mut() {
+        type_.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+    }
+
+    let child_script_config: ChildScriptConfig = child_script_config.into();
+    let witness_args = create_witness_args_output_type(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+fn cl_args(args: &dyn Fn(&[u8]) -> Vec<u8>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?;
+
+    let args = args(&hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let witness_args =
+        create_witness_args(&child_script_config, 0, &[(); 1].map(|_| Bytes::default()))?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+fn cl_config_cell_dep(
+    wrong_type_hash: bool,
+    wrong_data: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-config-cell-dep.json")?;
+
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?;
+    let mut data = child_script_config.as_slice().to_vec();
+    if wrong_data {
+        data.push(0);
+    }
+    repr_tx.mock_info.cell_deps[2].data = ckb_jsonrpc_types::JsonBytes::from_vec(data);
+    let config_cell_type: packed::Script = repr_tx.mock_info.cell_deps[2]
+        .output
+        .type_
+        .clone()
+        .unwrap()
+        .into();
+
+    let mut fields = vec![];
+    if wrong_type_hash {
+        fields.extend(hash(&hash(config_cell_type.as_slice())));
+    } else {
+        fields.extend(hash(config_cell_type.as_slice()));
+    }
+    fields.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args =
+        ckb_jsonrpc_types::JsonBytes::from_vec(create_args_v1(CONFIG_SOURCE_CELL_DEP, &fields));
+
+    let witness_args = create_witness_args_without_config(0, &[(); 1].map(|_| Bytes::default()))?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+fn cl_inner_witness_index_out_of_bounds() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx = read_tx_template("../ckb-debugger-tests/templates/cl-always-success.json")?;
+
+    let child_script_config = create_child_script_config(
+        &repr_tx,
+        &[1],
+        &[(); 1].map(|_| Bytes::default()),
+        &[&[0]],
+        false,
+    )?;
+    let mut args = vec![];
+    args.extend(hash(child_script_config.as_slice()));
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let witness_args = create_witness_args_with_inner_witness_index(
+        &child_script_config,
+        0,
+        &[(); 1].map(|_| Bytes::default()),
+        &[],
+        &[1],
+    )?;
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// ckb-auth run by spawn fails, then child-script-example exits with its own
+// error code instead of the one of ckb-auth.
+fn child_script_auth_spawn_failure() -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx =
+        read_tx_template("../ckb-debugger-tests/templates/child-script-success.json")?;
+
+    let private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let pubkey_hash = blake160(&private_key.pubkey().expect("pubkey").serialize());
+    let entry = create_script_from_cell_dep(&repr_tx, 1, false)?;
+    let args = create_auth_args_v1(0, &pubkey_hash, &entry, ENTRY_CATEGORY_SPAWN);
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    // signed by another key
+    let private_key = Privkey::from(H256::from([1u8; 32]));
+    let message = generate_sighash_all(&repr_tx, 0)?;
+    let sig = private_key
+        .sign_recoverable(&H256::from(message))
+        .expect("sign")
+        .serialize();
+    let witness_args = packed::WitnessArgs::new_builder()
+        .lock(Some(Bytes::from(sig)).pack())
+        .build();
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// child-script-example signing output 0 by SIGHASH_SINGLE, then the
+// transaction is changed by `change`, or signing with an unknown sighash mode
+// or an output range beyond the outputs.
+fn child_script_sighash(
+    mode: &[u8],
+    change: &dyn Fn(&mut ReprMockTransaction),
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx =
+        read_tx_template("../ckb-debugger-tests/templates/child-script-success.json")?;
+
+    let private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let pubkey_hash = blake160(&private_key.pubkey().expect("pubkey").serialize());
+    let entry = create_script_from_cell_dep(&repr_tx, 1, false)?;
+    let args = create_auth_args_v3(0, &pubkey_hash, &entry, ENTRY_CATEGORY_DYNAMIC_LINKING);
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let witness_args = packed::WitnessArgs::new_builder()
+        .lock(Some(Bytes::from(vec![0u8; 66])).pack())
+        .build();
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+    let message = generate_sighash(&repr_tx, 0, &[SIGHASH_SINGLE])?;
+    let sig = private_key
+        .sign_recoverable(&H256::from(message))
+        .expect("sign")
+        .serialize();
+    let witness_args = packed::WitnessArgs::new_builder()
+        .lock(Some(Bytes::from([mode.to_vec(), sig].concat())).pack())
+        .build();
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+    change(&mut repr_tx);
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+// child-script-example signing a building packet with an action for a script
+// not in the transaction, or with the action data changed after signing.
+fn child_script_cobuild(
+    script_hash: Option<[u8; 32]>,
+    change_data: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx =
+        read_tx_template("../ckb-debugger-tests/templates/child-script-success.json")?;
+
+    let private_key = Privkey::from(H256::from(G_PRIVKEY_BUF));
+    let pubkey_hash = blake160(&private_key.pubkey().expect("pubkey").serialize());
+    let entry = create_script_from_cell_dep(&repr_tx, 1, false)?;
+    let args = create_auth_args_v4(0, &pubkey_hash, &entry, ENTRY_CATEGORY_DYNAMIC_LINKING);
+    repr_tx.mock_info.inputs[0].output.lock.args = ckb_jsonrpc_types::JsonBytes::from_vec(args);
+
+    let lock_hash: [u8; 32] = packed::Script::from(repr_tx.mock_info.inputs[0].output.lock.clone())
+        .calc_script_hash()
+        .unpack();
+    let script_hash = script_hash.unwrap_or(lock_hash);
+    let message = create_message(vec![create_action(&[0x11; 32], &script_hash, b"transfer")]);
+    let witness_len = create_sighash_all(&message, &[0u8; 65]).total_size();
+    let witness_args = packed::WitnessArgs::new_builder()
+        .lock(Some(Bytes::from(vec![0u8; witness_len])).pack())
+        .build();
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+    let sighash = generate_sighash_all(&repr_tx, 0)?;
+    let sig = private_key
+        .sign_recoverable(&H256::from(hash_signing_message(&message, &sighash)))
+        .expect("sign")
+        .serialize();
+    let message = if change_data {
+        create_message(vec![create_action(&[0x11; 32], &script_hash, b"transfex")])
+    } else {
+        message
+    };
+    let witness_args = packed::WitnessArgs::new_builder()
+        .lock(Some(create_sighash_all(&message, &sig).as_bytes()).pack())
+        .build();
+    repr_tx.tx.witnesses[0] = ckb_jsonrpc_types::JsonBytes::from(witness_args.as_bytes().pack());
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
+fn child_script_auth_args(
+    args: &dyn Fn(&packed::Script) -> Vec<u8>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut repr_tx =
+        read_tx_template("../ckb-debugger-tests/templates/child-script-success.json")?;
+
+    let entry = create_script_from_cell_dep(&repr_tx, 1, false)?;
+    repr_tx.mock_info.inputs[0].output.lock.args =
+        ckb_jsonrpc_types::JsonBytes::from_vec(args(&entry));
+
+    let json = serde_json::to_string_pretty(&repr_tx).unwrap();
+    println!("{}", json);
+    Ok(())
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = std::env::args().collect();
     match args[1].as_str() {
         "cl-always-failure" => cl_always_failure()?,
-        "cl-child-script-config-hash-error" => cl_child_script_config_hash_error()?,
+        "cl-multi-witness-second-failure" => cl_multi_witness_second_failure()?,
+        // the legacy layout with a leading flag byte 0
+        "cl-child-script-config-hash-error" => {
+            cl_child_script_config_hash_error(&|hash| [&[0x00][..], hash].concat())?
+        }
+        "cl-child-script-config-hash-error-v1" => {
+            cl_child_script_config_hash_error(&|hash| create_args_v1(CONFIG_SOURCE_WITNESS, hash))?
+        }
+        "cl-script-config-missing" => cl_script_config_missing()?,
         "cl-child-script-sig-error" => cl_child_script_sig_error()?,
         "cl-cl-always-failure" => cl_cl_always_failure()?,
         "cl-index-error" => cl_index_error()?,
         "cl-vec-index-error" => cl_vec_index_error()?,
         "cl-witness-length-wrong" => cl_witness_length_wrong()?,
+        "cl-threshold-not-reached" => cl_threshold(&[0])?,
+        "cl-threshold-select-failure" => cl_threshold(&[0, 1])?,
+        "cl-threshold-wrong-selection" => cl_threshold(&[2, 0])?,
+        "cl-policy-not-satisfied" => cl_policy(0, &[5])?,
+        "cl-policy-branch-failure" => cl_policy(0, &[3, 4])?,
+        "cl-policy-wrong-selection" => cl_policy(0, &[1])?,
+        // the root is the threshold node, A is outside of it
+        "cl-policy-outside-root" => cl_policy(2, &[3, 5, 7])?,
+        "cl-since-not-satisfied" => cl_since(100, 99)?,
+        "cl-since-flags-mismatch" => cl_since(100, 0x8000_0000_0000_0000 | 100)?,
+        "cl-since-invalid" => cl_since(0x0100_0000_0000_0000 | 100, 100)?,
+        "cl-capacity-limit-exceeded" => cl_capacity_limit_exceeded(999, &[], 1000, 0)?,
+        "cl-capacity-limit-fee-exceeded" => cl_capacity_limit_exceeded(999, &[], 0, 1000)?,
+        "cl-capacity-limit-fee-over-allowance" => cl_capacity_limit_exceeded(0, &[1000], 0, 1001)?,
+        "cl-policy-capacity-limit" => cl_policy_capacity_limit()?,
+        "cl-args-slice-out-of-range" => cl_args_slice_out_of_range()?,
+        "cl-inner-witness-index-out-of-bounds" => cl_inner_witness_index_out_of_bounds()?,
+        "cl-memory-limit-too-large" => cl_resource_limit(&[9], &[])?,
+        "cl-cycles-limit-too-large" => cl_resource_limit(&[], &[70_000_001])?,
+        "cl-resource-limit-wrong-length" => cl_resource_limit(&[1, 1], &[])?,
+        "cl-cycles-limit-exceeded" => cl_resource_limit(&[], &[1])?,
+        // child-script-dl-always-success run by dynamic linking, then
+        // child-script-always-success run by exec
+        "cl-exec-fallback-dl-cycles-limit-exceeded" => {
+            cl_exec_fallback_cycles_limit(&[2, 1], &[1, 0])?
+        }
+        "cl-exec-fallback-exec-cycles-limit" => cl_exec_fallback_cycles_limit(&[1], &[1_000_000])?,
+        "cl-multi-witness-since-not-satisfied" => cl_multi_witness(0x8000_0000_0000_0000 | 5)?,
+        "cl-shared-message-unmasked" => cl_shared_message_unmasked()?,
+        "ct-mint-always-failure" => ct_mint_always_failure()?,
+        "ct-mint-since-not-satisfied" => ct_mint_since_not_satisfied()?,
+        "child-script-auth-args-unknown-version" => child_script_auth_args(&|entry| {
+            let mut args = create_auth_args_v1(0, &[0; 20], entry, ENTRY_CATEGORY_DYNAMIC_LINKING);
+            args[0] = 0xff;
+            args
+        })?,
+        "child-script-auth-spawn-failure" => child_script_auth_spawn_failure()?,
+        "child-script-sighash-output-changed" => child_script_sighash(&[SIGHASH_SINGLE], &|tx| {
+            tx.tx.outputs[0].capacity = 0x1000u64.into();
+        })?,
+        // a cell dep added after signing
+        "child-script-sighash-cell-dep-added" => child_script_sighash(&[SIGHASH_SINGLE], &|tx| {
+            let mut cell_dep = tx.mock_info.cell_deps[1].clone();
+            cell_dep.cell_dep.out_point.index = (tx.mock_info.cell_deps.len() as u32).into();
+            tx.tx.cell_deps.push(cell_dep.cell_dep.clone());
+            tx.mock_info.cell_deps.push(cell_dep);
+        })?,
+        "child-script-sighash-unknown-mode" => child_script_sighash(&[5], &|_| {})?,
+        "child-script-sighash-range-out-of-bounds" => {
+            child_script_sighash(&create_sighash_output_range(false, 0, u32::MAX), &|_| {})?
+        }
+        "child-script-cobuild-unknown-action" => child_script_cobuild(Some([0x22; 32]), false)?,
+        "child-script-cobuild-action-changed" => child_script_cobuild(None, true)?,
+        "child-script-auth-args-empty-identity" => child_script_auth_args(&|entry| {
+            create_auth_args_v2(0, &[], entry, ENTRY_CATEGORY_DYNAMIC_LINKING)
+        })?,
+        "cl-args-unknown-version" => {
+            cl_args(&|hash| [&[2, CONFIG_SOURCE_WITNESS][..], hash].concat())?
+        }
+        "cl-args-reserved-flags" => cl_args(&|hash| create_args_v1(0x08, hash))?,
+        "cl-args-trailing-bytes" => {
+            cl_args(&|hash| [create_args_v1(CONFIG_SOURCE_WITNESS, hash), vec![0]].concat())?
+        }
+        "cl-config-cell-dep-not-found" => cl_config_cell_dep(true, false)?,
+        "cl-config-cell-dep-mismatched" => cl_config_cell_dep(false, true)?,
+        "cl-args-wrong-length" => {
+            cl_args(&|hash| create_args_v1(CONFIG_SOURCE_WITNESS, &hash[1..]))?
+        }
         _ => unreachable!(),
     };
     Ok(())
@@ -0,0 +1,270 @@
+// Decode the exit code of ckb-combine-lock into a human-readable explanation.
+use crate::hash::hash;
+use ckb_combine_lock_types::combine_lock::{
+    ChildScript, ChildScriptConfig, ChildScriptConfigV2, CombineLockWitness, CombineLockWitnessV2,
+};
+use ckb_mock_tx_types::ReprMockTransaction;
+use ckb_types::packed;
+use ckb_types::prelude::*;
+use molecule::prelude::*;
+
+// Keep it in sync with ckb-lock-common/src/combine_lock/error.rs
+const COMBINE_LOCK_ERRORS: &[(i8, &str)] = &[
+    (1, "IndexOutOfBound"),
+    (2, "ItemMissing"),
+    (3, "LengthNotEnough"),
+    (4, "Encoding"),
+    (80, "WrongFormat"),
+    (81, "WrongHashType"),
+    (82, "ChildScriptArrayIndexOutOfBounds"),
+    (83, "CombineLockWitnessIndexOutOfBounds"),
+    (84, "UnlockFailed"),
+    (85, "InnerWitnessIndexOutOfBounds"),
+    (86, "ChildScriptHashMismatched"),
+    (87, "CommonError"),
+    (88, "InvalidThreshold"),
+    (89, "ThresholdNotReached"),
+    (90, "InvalidChildScriptSelection"),
+    (91, "InvalidPolicy"),
+    (92, "PolicyNotSatisfied"),
+    (93, "InvalidSince"),
+    (94, "SinceNotSatisfied"),
+    (95, "InvalidCapacityLimit"),
+    (96, "CapacityLimitExceeded"),
+    (97, "LoadChildScriptError"),
+    (98, "UnknownArgsVersion"),
+    (99, "InvalidArgs"),
+    (100, "ConfigCellDepNotFound"),
+    (101, "InvalidResourceLimit"),
+    (102, "CyclesLimitExceeded"),
+    (103, "InvalidArgsSlice"),
+    (104, "DLMemoryNotEnough"),
+];
+
+// Exit code of a panic in ckb-combine-lock, by the panic handler of ckb-std.
+const PANIC_EXIT_CODE: i8 = -1;
+
+/// A failed child script, unpacked from the exit code of ckb-combine-lock.
+pub struct ChildScriptFailure {
+    /// Position in running order. 6 means 6 or later.
+    pub position: usize,
+    /// Exit code of the child script. None if it's not in [1, 15].
+    pub exit_code: Option<i8>,
+}
+
+// [-16, -1] are not packed child script failures, see
+// ckb-lock-common/src/combine_lock/error.rs
+pub fn unpack_exit_code(code: i8) -> Option<ChildScriptFailure> {
+    if code >= -16 {
+        return None;
+    }
+    let code = code as u8;
+    let exit_code = (code & 0x0f) as i8;
+    Some(ChildScriptFailure {
+        position: ((code >> 4) & 0x07) as usize,
+        exit_code: if exit_code == 0 {
+            None
+        } else {
+            Some(exit_code)
+        },
+    })
+}
+
+fn verification_error(err: molecule::error::VerificationError) -> anyhow::Error {
+    anyhow::anyhow!("{}", err)
+}
+
+// Witness index of the `group_index`th input in the script group of the first
+// input.
+fn group_witness_index(repr_tx: &ReprMockTransaction, group_index: usize) -> Option<usize> {
+    let lock = &repr_tx.mock_info.inputs.first()?.output.lock;
+    repr_tx
+        .mock_info
+        .inputs
+        .iter()
+        .enumerate()
+        .filter(|(_, input)| &input.output.lock == lock)
+        .map(|(index, _)| index)
+        .nth(group_index)
+}
+
+fn combine_lock_witness(
+    repr_tx: &ReprMockTransaction,
+    index: usize,
+) -> Result<ckb_types::bytes::Bytes, anyhow::Error> {
+    let witness = repr_tx
+        .tx
+        .witnesses
+        .get(index)
+        .ok_or_else(|| anyhow::anyhow!("witness {} is not in transaction", index))?
+        .as_bytes();
+    let witness_args = packed::WitnessArgs::from_slice(witness).map_err(verification_error)?;
+    Ok(witness_args
+        .lock()
+        .to_opt()
+        .ok_or_else(|| anyhow::anyhow!("no lock in witness {}", index))?
+        .unpack())
+}
+
+// Child scripts in running order, resolved from the witness at
+// `witness_index`. The script config is from the first witness in the script
+// group, it can be omitted in others.
+fn running_child_scripts(
+    repr_tx: &ReprMockTransaction,
+    witness_index: usize,
+) -> Result<Vec<ChildScript>, anyhow::Error> {
+    let lock = combine_lock_witness(repr_tx, witness_index)?;
+    let witness = CombineLockWitness::from_compatible_slice(&lock).map_err(verification_error)?;
+    let selection: Vec<u8> = if witness.field_count() >= 4 {
+        let witness =
+            CombineLockWitnessV2::from_compatible_slice(&lock).map_err(verification_error)?;
+        witness.selection().into_iter().map(u8::from).collect()
+    } else {
+        vec![]
+    };
+    let index: u16 = witness.index().unpack();
+    // the first input is also the first one in its script group
+    let first_lock = combine_lock_witness(repr_tx, 0)?;
+    let config = CombineLockWitness::from_compatible_slice(&first_lock)
+        .map_err(verification_error)?
+        .script_config()
+        .to_opt()
+        .ok_or_else(|| anyhow::anyhow!("script config is not in witness"))?;
+    let config_bytes = config.as_bytes();
+
+    let array_indexes: Vec<usize> = if config.has_extra_fields() {
+        let config = ChildScriptConfigV2::from_compatible_slice(&config_bytes)
+            .map_err(verification_error)?;
+        if !config.policy().is_empty() {
+            selection
+                .iter()
+                .map(|&node| {
+                    let node = config.policy().get(node as usize).unwrap();
+                    u8::from(node.children().get(0).unwrap()) as usize
+                })
+                .collect()
+        } else {
+            entry_child_scripts(&config.into(), index as usize, &selection)?
+        }
+    } else {
+        entry_child_scripts(&config, index as usize, &selection)?
+    };
+    let array = config.array();
+    array_indexes
+        .into_iter()
+        .map(|i| {
+            array
+                .get(i)
+                .ok_or_else(|| anyhow::anyhow!("child script {} is not in array", i))
+        })
+        .collect()
+}
+
+fn entry_child_scripts(
+    config: &ChildScriptConfig,
+    index: usize,
+    selection: &[u8],
+) -> Result<Vec<usize>, anyhow::Error> {
+    let entry = config
+        .index()
+        .get(index)
+        .ok_or_else(|| anyhow::anyhow!("index {} is not in script config", index))?;
+    if selection.is_empty() {
+        Ok(entry.into_iter().map(|i| u8::from(i) as usize).collect())
+    } else {
+        Ok(selection
+            .iter()
+            .filter_map(|&p| entry.get(p as usize))
+            .map(|i| u8::from(i) as usize)
+            .collect())
+    }
+}
+
+// Find the cell dep with the code of child script.
+fn find_cell_dep(repr_tx: &ReprMockTransaction, child_script: &ChildScript) -> Option<usize> {
+    let code_hash = child_script.code_hash();
+    repr_tx.mock_info.cell_deps.iter().position(|cell_dep| {
+        if u8::from(child_script.hash_type()) == 1 {
+            match &cell_dep.output.type_ {
+                Some(script) => {
+                    let script: packed::Script = script.clone().into();
+                    hash(script.as_slice()) == code_hash.as_slice()
+                }
+                None => false,
+            }
+        } else {
+            hash(cell_dep.data.as_bytes()) == code_hash.as_slice()
+        }
+    })
+}
+
+/// Explain an exit code of ckb-combine-lock. With the transaction, the failed
+/// child script is resolved from `ChildScriptConfig` in the witness.
+///
+/// * `group_index` - index of the failed witness in the script group, logged
+/// by ckb-combine-lock as "group witness N failed". The exit code has no room
+/// for it.
+pub fn explain_exit_code(
+    code: i8,
+    repr_tx: Option<&ReprMockTransaction>,
+    group_index: usize,
+) -> String {
+    if code == 0 {
+        return "success".into();
+    }
+    if code == PANIC_EXIT_CODE {
+        return "combine lock panicked, see the log of ckb-debugger".into();
+    }
+    let failure = match unpack_exit_code(code) {
+        Some(failure) => failure,
+        None => {
+            return match COMBINE_LOCK_ERRORS.iter().find(|(c, _)| *c == code) {
+                Some((_, name)) => format!("combine lock failed with error {} ({})", name, code),
+                None => format!("unknown exit code {}", code),
+            }
+        }
+    };
+    let mut result = if failure.position == 6 {
+        String::from("child script at position 6 or later (in running order) failed")
+    } else {
+        format!(
+            "child script at position {} (in running order) failed",
+            failure.position
+        )
+    };
+    match failure.exit_code {
+        Some(exit_code) => result += &format!(" with exit code {}", exit_code),
+        None => {
+            result += " with an unknown exit code not in [1, 15], it's logged by ckb-combine-lock \
+                as \"child script at position N exited with M\""
+        }
+    }
+    let repr_tx = match repr_tx {
+        Some(repr_tx) => repr_tx,
+        None => return result,
+    };
+    let witness_index = match group_witness_index(repr_tx, group_index) {
+        Some(witness_index) => witness_index,
+        None => return result + &format!("\ngroup witness {} is not found", group_index),
+    };
+    result += &format!(", in witnesses[{}]", witness_index);
+    match running_child_scripts(repr_tx, witness_index) {
+        Ok(child_scripts) if failure.position < 6 => match child_scripts.get(failure.position) {
+            Some(child_script) => {
+                result += &format!(
+                    "\nchild script: code_hash = {}, hash_type = {}, args = {}",
+                    child_script.code_hash(),
+                    u8::from(child_script.hash_type()),
+                    child_script.args()
+                );
+                if let Some(index) = find_cell_dep(repr_tx, child_script) {
+                    result += &format!(", code in cell_deps[{}]", index);
+                }
+            }
+            None => result += "\nchild script is not found in script config",
+        },
+        Ok(_) => {}
+        Err(err) => result += &format!("\nfailed to resolve child script: {}", err),
+    }
+    result
+}
@@ -1,25 +1,35 @@
 pub mod auto_complete;
+pub mod exit_code;
 pub mod global_registry;
 pub mod hash;
+#[path = "../../ckb-lock-common/src/intersection.rs"]
+mod intersection;
 
 use anyhow;
 use anyhow::Context;
 use auto_complete::auto_complete;
+use ckb_combine_lock_types::cobuild::{Action, ActionVec, Message, SighashAll};
 use ckb_combine_lock_types::combine_lock::{
-    ChildScriptArray, ChildScriptConfig, ChildScriptConfigOpt, ChildScriptVec, ChildScriptVecVec,
-    CombineLockWitness, Uint16,
+    ArgsSlice, ArgsSliceVec, CapacityLimitVec, ChildScriptArray, ChildScriptConfig,
+    ChildScriptConfigOpt, ChildScriptConfigV2, ChildScriptConfigV2Builder, ChildScriptVec,
+    ChildScriptVecVec, CombineLockWitness, CombineLockWitnessReader, CombineLockWitnessV2,
+    CyclesLimitVec, MemoryLimitVec, PolicyNode, PolicyNodeVec, SinceVec, ThresholdVec, Uint16,
+};
+use ckb_combine_lock_types::envelope::{
+    find_unknown_action, update_signing_message, COBUILD_PERSONALIZATION,
 };
 use ckb_debugger_api::embed::Embed;
-use ckb_hash::new_blake2b;
+use ckb_hash::{blake2b_256, new_blake2b, Blake2b, Blake2bBuilder};
 use ckb_mock_tx_types::{MockTransaction, ReprMockTransaction};
 use ckb_types::core::ScriptHashType;
 use ckb_types::packed;
 use ckb_types::prelude::*;
 use hash::hash;
+use intersection::get_intersection;
 use molecule::bytes::Bytes;
 use molecule::prelude::*;
 use serde_json::from_str as from_json_str;
-use std::{fs::read_to_string, path::PathBuf};
+use std::{fs::read_to_string, ops::Range, path::PathBuf};
 
 pub fn read_tx_template(file_name: &str) -> Result<ReprMockTransaction, anyhow::Error> {
     let mock_tx =
@@ -78,6 +88,84 @@ pub fn create_script_from_cell_dep(
     Ok(script)
 }
 
+// Version 1 of combine lock script args: | version | flags | fields | user args |.
+// Legacy args are the 32 bytes ChildScriptConfig hash only.
+pub const ARGS_VERSION: u8 = 1;
+pub const CONFIG_SOURCE_WITNESS: u8 = 0;
+pub const CONFIG_SOURCE_GLOBAL_REGISTRY: u8 = 1;
+pub const CONFIG_SOURCE_CELL_DEP: u8 = 2;
+pub const ARGS_FLAG_USER_ARGS: u8 = 4;
+
+pub fn create_args_v1(flags: u8, fields: &[u8]) -> Vec<u8> {
+    let mut args = vec![ARGS_VERSION, flags];
+    args.extend_from_slice(fields);
+    args
+}
+
+// Version 1 of child-script-example args, carrying the ckb-auth entry:
+// | version | auth id | pubkey hash | code hash | hash type | entry category |.
+// Version 2 has a variable-length identity instead of the pubkey hash, at the
+// end. Legacy args are | auth id | pubkey hash | only.
+pub const AUTH_ARGS_VERSION_1: u8 = 1;
+pub const AUTH_ARGS_VERSION_2: u8 = 2;
+// Version 3 is the same as version 2, but the witness starts with a sighash mode.
+pub const AUTH_ARGS_VERSION_3: u8 = 3;
+// Version 4 is the same as version 2, but the witness is a SighashAll.
+pub const AUTH_ARGS_VERSION_4: u8 = 4;
+pub const ENTRY_CATEGORY_EXEC: u8 = 0;
+pub const ENTRY_CATEGORY_DYNAMIC_LINKING: u8 = 1;
+pub const ENTRY_CATEGORY_SPAWN: u8 = 2;
+
+pub fn create_auth_args_v1(
+    auth_id: u8,
+    pubkey_hash: &[u8; 20],
+    entry: &packed::Script,
+    entry_category: u8,
+) -> Vec<u8> {
+    let mut args = vec![AUTH_ARGS_VERSION_1, auth_id];
+    args.extend_from_slice(pubkey_hash);
+    args.extend_from_slice(entry.code_hash().as_slice());
+    args.push(entry.hash_type().into());
+    args.push(entry_category);
+    args
+}
+
+pub fn create_auth_args_v2(
+    auth_id: u8,
+    identity: &[u8],
+    entry: &packed::Script,
+    entry_category: u8,
+) -> Vec<u8> {
+    let mut args = vec![AUTH_ARGS_VERSION_2, auth_id];
+    args.extend_from_slice(entry.code_hash().as_slice());
+    args.push(entry.hash_type().into());
+    args.push(entry_category);
+    args.extend_from_slice(identity);
+    args
+}
+
+pub fn create_auth_args_v3(
+    auth_id: u8,
+    identity: &[u8],
+    entry: &packed::Script,
+    entry_category: u8,
+) -> Vec<u8> {
+    let mut args = create_auth_args_v2(auth_id, identity, entry, entry_category);
+    args[0] = AUTH_ARGS_VERSION_3;
+    args
+}
+
+pub fn create_auth_args_v4(
+    auth_id: u8,
+    identity: &[u8],
+    entry: &packed::Script,
+    entry_category: u8,
+) -> Vec<u8> {
+    let mut args = create_auth_args_v2(auth_id, identity, entry, entry_category);
+    args[0] = AUTH_ARGS_VERSION_4;
+    args
+}
+
 pub fn create_child_script_config(
     repr_tx: &ReprMockTransaction,
     cell_dep_index: &[usize],
@@ -109,6 +197,93 @@ pub fn create_child_script_config(
     Ok(child_script_config)
 }
 
+// Returns a builder with `array` and `index` filled, the other fields of
+// ChildScriptConfigV2 are empty by default.
+pub fn create_child_script_config_v2(
+    repr_tx: &ReprMockTransaction,
+    cell_dep_index: &[usize],
+    args: &[Bytes],
+    vec_vec: &[&[u8]],
+    use_type: bool,
+) -> Result<ChildScriptConfigV2Builder, anyhow::Error> {
+    let child_script_config =
+        create_child_script_config(repr_tx, cell_dep_index, args, vec_vec, use_type)?;
+    Ok(ChildScriptConfigV2::new_builder()
+        .array(child_script_config.array())
+        .index(child_script_config.index()))
+}
+
+pub fn create_threshold_vec(threshold: &[u8]) -> ThresholdVec {
+    let mut threshold_builder = ThresholdVec::new_builder();
+    for &i in threshold {
+        threshold_builder = threshold_builder.push(i.into());
+    }
+    threshold_builder.build()
+}
+
+// Every node is (kind, threshold, children).
+pub fn create_policy(policy: &[(u8, u8, &[u8])]) -> PolicyNodeVec {
+    let mut policy_builder = PolicyNodeVec::new_builder();
+    for &(kind, threshold, children) in policy {
+        let mut children_builder = ChildScriptVec::new_builder();
+        for &i in children {
+            children_builder = children_builder.push(i.into());
+        }
+        let node = PolicyNode::new_builder()
+            .kind(kind.into())
+            .threshold(threshold.into())
+            .children(children_builder.build())
+            .build();
+        policy_builder = policy_builder.push(node);
+    }
+    policy_builder.build()
+}
+
+pub fn create_since_vec(since: &[u64]) -> SinceVec {
+    let mut since_builder = SinceVec::new_builder();
+    for &i in since {
+        since_builder = since_builder.push(i.pack());
+    }
+    since_builder.build()
+}
+
+pub fn create_capacity_limit_vec(limit: &[u64]) -> CapacityLimitVec {
+    let mut limit_builder = CapacityLimitVec::new_builder();
+    for &i in limit {
+        limit_builder = limit_builder.push(i.pack());
+    }
+    limit_builder.build()
+}
+
+pub fn create_memory_limit_vec(limit: &[u8]) -> MemoryLimitVec {
+    let mut limit_builder = MemoryLimitVec::new_builder();
+    for &i in limit {
+        limit_builder = limit_builder.push(i.into());
+    }
+    limit_builder.build()
+}
+
+pub fn create_cycles_limit_vec(limit: &[u64]) -> CyclesLimitVec {
+    let mut limit_builder = CyclesLimitVec::new_builder();
+    for &i in limit {
+        limit_builder = limit_builder.push(i.pack());
+    }
+    limit_builder.build()
+}
+
+// Every slice is (offset, length) in user args.
+pub fn create_args_slice_vec(slices: &[(u16, u16)]) -> ArgsSliceVec {
+    let mut slice_builder = ArgsSliceVec::new_builder();
+    for &(offset, length) in slices {
+        let slice = ArgsSlice::new_builder()
+            .offset(Uint16::new_unchecked(offset.to_le_bytes().to_vec().into()))
+            .length(Uint16::new_unchecked(length.to_le_bytes().to_vec().into()))
+            .build();
+        slice_builder = slice_builder.push(slice);
+    }
+    slice_builder.build()
+}
+
 pub fn create_combine_lock_witness(
     child_script_config: &ChildScriptConfig,
     index: u16,
@@ -143,6 +318,98 @@ pub fn create_witness_args(
     Ok(witness_args)
 }
 
+// When ChildScriptConfig is not in witness, e.g. it's in a cell_dep.
+pub fn create_witness_args_without_config(
+    index: u16,
+    inner_witness: &[Bytes],
+) -> Result<packed::WitnessArgs, anyhow::Error> {
+    let mut inner_witness_builder = packed::BytesVec::new_builder();
+    for i in inner_witness {
+        inner_witness_builder = inner_witness_builder.push(i.clone().pack())
+    }
+    let combine_lock_witness = CombineLockWitness::new_builder()
+        .index(Uint16::new_unchecked(index.to_le_bytes().to_vec().into()))
+        .inner_witness(inner_witness_builder.build())
+        .build();
+    let witness_args = packed::WitnessArgs::new_builder()
+        .lock(Some(combine_lock_witness.as_bytes()).pack())
+        .build();
+    Ok(witness_args)
+}
+
+// For combine type without group input, e.g. minting.
+pub fn create_witness_args_output_type(
+    child_script_config: &ChildScriptConfig,
+    index: u16,
+    inner_witness: &[Bytes],
+) -> Result<packed::WitnessArgs, anyhow::Error> {
+    let combine_lock_witness =
+        create_combine_lock_witness(child_script_config, index, inner_witness)?;
+    let witness_args = packed::WitnessArgs::new_builder()
+        .output_type(Some(combine_lock_witness.as_bytes()).pack())
+        .build();
+    Ok(witness_args)
+}
+
+pub fn create_combine_lock_witness_v2(
+    child_script_config: &ChildScriptConfig,
+    index: u16,
+    inner_witness: &[Bytes],
+    selection: &[u8],
+) -> Result<CombineLockWitnessV2, anyhow::Error> {
+    let combine_lock_witness =
+        create_combine_lock_witness(child_script_config, index, inner_witness)?;
+    let mut selection_builder = ChildScriptVec::new_builder();
+    for &i in selection {
+        selection_builder = selection_builder.push(i.into());
+    }
+    let combine_lock_witness = CombineLockWitnessV2::new_builder()
+        .index(combine_lock_witness.index())
+        .inner_witness(combine_lock_witness.inner_witness())
+        .script_config(combine_lock_witness.script_config())
+        .selection(selection_builder.build())
+        .build();
+    Ok(combine_lock_witness)
+}
+
+pub fn create_witness_args_v2(
+    child_script_config: &ChildScriptConfig,
+    index: u16,
+    inner_witness: &[Bytes],
+    selection: &[u8],
+) -> Result<packed::WitnessArgs, anyhow::Error> {
+    let combine_lock_witness =
+        create_combine_lock_witness_v2(child_script_config, index, inner_witness, selection)?;
+    let witness_args = packed::WitnessArgs::new_builder()
+        .lock(Some(combine_lock_witness.as_bytes()).pack())
+        .build();
+    Ok(witness_args)
+}
+
+// Child scripts share the inner witness at `inner_witness_index`, one for
+// every chosen child script.
+pub fn create_witness_args_with_inner_witness_index(
+    child_script_config: &ChildScriptConfig,
+    index: u16,
+    inner_witness: &[Bytes],
+    selection: &[u8],
+    inner_witness_index: &[u8],
+) -> Result<packed::WitnessArgs, anyhow::Error> {
+    let mut index_builder = ChildScriptVec::new_builder();
+    for &i in inner_witness_index {
+        index_builder = index_builder.push(i.into());
+    }
+    let combine_lock_witness =
+        create_combine_lock_witness_v2(child_script_config, index, inner_witness, selection)?
+            .as_builder()
+            .inner_witness_index(index_builder.build())
+            .build();
+    let witness_args = packed::WitnessArgs::new_builder()
+        .lock(Some(combine_lock_witness.as_bytes()).pack())
+        .build();
+    Ok(witness_args)
+}
+
 // Now, only support lock script
 fn get_group(index: usize, repr_tx: &ReprMockTransaction) -> Vec<usize> {
     let lock = repr_tx.mock_info.inputs[index].output.lock.clone();
@@ -158,14 +425,23 @@ fn get_group(index: usize, repr_tx: &ReprMockTransaction) -> Vec<usize> {
 pub fn generate_sighash_all(
     tx: &ReprMockTransaction,
     index: usize,
+) -> Result<[u8; 32], anyhow::Error> {
+    generate_sighash_all_with_masks(tx, index, &[])
+}
+
+// Same as generate_sighash_all_with_masks in ckb-lock-common. Each mask is a
+// range in the witness at the given position of the script group, it's zeroed
+// chunk by chunk as the script does.
+pub fn generate_sighash_all_with_masks(
+    tx: &ReprMockTransaction,
+    index: usize,
+    masks: &[(usize, Range<usize>)],
 ) -> Result<[u8; 32], anyhow::Error> {
     let lock_indexes = get_group(index, &tx);
     if lock_indexes.is_empty() {
         panic!("not get lock index");
     }
 
-    let witness = tx.tx.witnesses.get(lock_indexes[0]).unwrap();
-
     let mut blake2b = new_blake2b();
     let mut message = [0u8; 32];
 
@@ -174,18 +450,17 @@ pub fn generate_sighash_all(
     let tx_hash = mock_tx.tx.calc_tx_hash();
     blake2b.update(&tx_hash.raw_data());
     // println!("--hash: {:02X?}", &tx_hash.raw_data().to_vec());
-    let witness_data = witness.as_bytes();
-    blake2b.update(&(witness_data.len() as u64).to_le_bytes());
-    blake2b.update(&witness_data);
 
     // group
-    if lock_indexes.len() > 1 {
-        for i in 1..lock_indexes.len() {
-            let witness = mock_tx.tx.witnesses().get(lock_indexes[i]).unwrap();
-
-            blake2b.update(&(witness.len() as u64).to_le_bytes());
-            blake2b.update(&witness.raw_data());
-        }
+    for (i, &lock_index) in lock_indexes.iter().enumerate() {
+        let witness = match mock_tx.tx.witnesses().get(lock_index) {
+            Some(witness) => witness.raw_data(),
+            None if i == 0 => anyhow::bail!("no witness at {}", lock_index),
+            None => break,
+        };
+        let witness = zero_masks(&witness, i, masks)?;
+        blake2b.update(&(witness.len() as u64).to_le_bytes());
+        blake2b.update(&witness);
     }
     let witness_len = std::cmp::max(tx.tx.inputs.len(), mock_tx.tx.witnesses().len());
     if tx.tx.inputs.len() < witness_len {
@@ -200,3 +475,235 @@ pub fn generate_sighash_all(
     blake2b.finalize(&mut message);
     Ok(message)
 }
+
+// The chunk size of ChunksLoader in ckb-lock-common.
+const CHUNK_SIZE: usize = 32768;
+
+fn zero_masks(
+    witness: &[u8],
+    position: usize,
+    masks: &[(usize, Range<usize>)],
+) -> Result<Vec<u8>, anyhow::Error> {
+    let mut result = witness.to_vec();
+    for (_, mask) in masks.iter().filter(|(p, _)| *p == position) {
+        if mask.end > witness.len() {
+            anyhow::bail!(
+                "mask {:?} is out of the witness of {} bytes",
+                mask,
+                witness.len()
+            );
+        }
+        for (i, chunk) in result.chunks_mut(CHUNK_SIZE).enumerate() {
+            let chunk_offset = i * CHUNK_SIZE;
+            if let Some(slice) =
+                get_intersection(chunk_offset..chunk_offset + chunk.len(), mask.clone())
+            {
+                chunk[slice.start - chunk_offset..slice.end - chunk_offset].fill(0);
+            }
+        }
+    }
+    Ok(result)
+}
+
+// Ranges of all inner witnesses in `witness`, a WitnessArgs with a
+// CombineLockWitness in its lock. They're the masks of the message shared by
+// the child scripts of combine lock, relative to the witness.
+pub fn inner_witness_masks(witness: &[u8]) -> Result<Vec<Range<usize>>, anyhow::Error> {
+    let witness_args = packed::WitnessArgsReader::from_slice(witness)?;
+    let lock = witness_args
+        .lock()
+        .to_opt()
+        .ok_or_else(|| anyhow::anyhow!("no lock in witness"))?
+        .raw_data();
+    let combine_lock_witness = CombineLockWitnessReader::from_compatible_slice(lock)?;
+    let inner_witness = combine_lock_witness.inner_witness();
+    let masks = (0..inner_witness.len())
+        .map(|i| {
+            let data = inner_witness.get_unchecked(i).raw_data();
+            let offset = data.as_ptr() as usize - witness.as_ptr() as usize;
+            offset..offset + data.len()
+        })
+        .collect();
+    Ok(masks)
+}
+
+// Sighash modes, see generate_sighash.rs in ckb-lock-common
+pub const SIGHASH_ALL: u8 = 1;
+pub const SIGHASH_NONE: u8 = 2;
+pub const SIGHASH_SINGLE: u8 = 3;
+pub const SIGHASH_OUTPUT_RANGE: u8 = 4;
+pub const SIGHASH_WITNESS_DIGEST: u8 = 0x40;
+pub const SIGHASH_ANYONECANPAY: u8 = 0x80;
+
+pub fn create_sighash_output_range(anyone_can_pay: bool, start: u32, end: u32) -> Vec<u8> {
+    let mut mode = vec![SIGHASH_OUTPUT_RANGE];
+    if anyone_can_pay {
+        mode[0] |= SIGHASH_ANYONECANPAY;
+    }
+    mode.extend_from_slice(&start.to_le_bytes());
+    mode.extend_from_slice(&end.to_le_bytes());
+    mode
+}
+
+// The message of sighash `mode`, encoded as it's in the witness. Like
+// generate_sighash_all, the witnesses are digested as they are, so the ranges
+// zeroed by the script should be zeroed before calling it.
+pub fn generate_sighash(
+    tx: &ReprMockTransaction,
+    index: usize,
+    mode: &[u8],
+) -> Result<[u8; 32], anyhow::Error> {
+    if mode == [SIGHASH_ALL] {
+        return generate_sighash_all(tx, index);
+    }
+    let lock_indexes = get_group(index, &tx);
+    let mock_tx: MockTransaction = tx.clone().into();
+    let inputs = mock_tx.tx.inputs();
+    let outputs = mock_tx.tx.outputs();
+    let outputs_data = mock_tx.tx.outputs_data();
+    let witnesses = mock_tx.tx.witnesses();
+
+    let mut blake2b = new_blake2b();
+    blake2b.update(mode);
+
+    let witness_digest = mode[0] & SIGHASH_WITNESS_DIGEST != 0;
+    let outputs_mode = mode[0] & !(SIGHASH_ANYONECANPAY | SIGHASH_WITNESS_DIGEST);
+    if outputs_mode == SIGHASH_ALL && mode[0] & SIGHASH_ANYONECANPAY == 0 {
+        // only with SIGHASH_WITNESS_DIGEST
+        blake2b.update(&mock_tx.tx.calc_tx_hash().raw_data());
+        update_witness_digests(&mut blake2b, lock_indexes.iter().map(|&i| witnesses.get(i)));
+        update_witness_digests(
+            &mut blake2b,
+            (inputs.len()..witnesses.len()).map(|i| witnesses.get(i)),
+        );
+        let mut message = [0u8; 32];
+        blake2b.finalize(&mut message);
+        return Ok(message);
+    }
+
+    let raw_tx = packed::Transaction::from(tx.tx.clone()).raw();
+    blake2b.update(raw_tx.version().as_slice());
+    blake2b.update(raw_tx.cell_deps().as_slice());
+    blake2b.update(raw_tx.header_deps().as_slice());
+
+    let input_indexes: Vec<usize> = if mode[0] & SIGHASH_ANYONECANPAY != 0 {
+        lock_indexes.clone()
+    } else {
+        (0..inputs.len()).collect()
+    };
+    blake2b.update(&(input_indexes.len() as u64).to_le_bytes());
+    for i in input_indexes {
+        blake2b.update(&(i as u64).to_le_bytes());
+        blake2b.update(inputs.get(i).unwrap().as_slice());
+    }
+
+    let output_indexes: Vec<usize> = match outputs_mode {
+        SIGHASH_ALL => (0..outputs.len()).collect(),
+        SIGHASH_NONE => vec![],
+        SIGHASH_SINGLE => lock_indexes.clone(),
+        SIGHASH_OUTPUT_RANGE => {
+            let start = u32::from_le_bytes(mode[1..5].try_into().unwrap());
+            let end = u32::from_le_bytes(mode[5..9].try_into().unwrap());
+            (start as usize..end as usize).collect()
+        }
+        _ => anyhow::bail!("unknown sighash mode: {}", mode[0]),
+    };
+    blake2b.update(&(output_indexes.len() as u64).to_le_bytes());
+    for i in output_indexes {
+        let data = outputs_data.get(i).unwrap();
+        blake2b.update(&(i as u64).to_le_bytes());
+        blake2b.update(outputs.get(i).unwrap().as_slice());
+        blake2b.update(packed::CellOutput::calc_data_hash(&data.raw_data()).as_slice());
+    }
+
+    if witness_digest {
+        update_witness_digests(&mut blake2b, lock_indexes.iter().map(|&i| witnesses.get(i)));
+    } else {
+        for i in lock_indexes {
+            let witness = witnesses.get(i).unwrap();
+            blake2b.update(&(witness.len() as u64).to_le_bytes());
+            blake2b.update(&witness.raw_data());
+        }
+    }
+
+    let mut message = [0u8; 32];
+    blake2b.finalize(&mut message);
+    Ok(message)
+}
+
+// The witness digests of SIGHASH_WITNESS_DIGEST, see sighash_cache.rs in
+// ckb-lock-common: the count, then the length and the hash of each witness.
+fn update_witness_digests<I: ExactSizeIterator<Item = Option<packed::Bytes>>>(
+    blake2b: &mut Blake2b,
+    witnesses: I,
+) {
+    blake2b.update(&(witnesses.len() as u64).to_le_bytes());
+    for witness in witnesses {
+        let witness = witness.unwrap().raw_data();
+        blake2b.update(&(witness.len() as u64).to_le_bytes());
+        blake2b.update(&blake2b_256(&witness));
+    }
+}
+
+pub fn create_action(script_info_hash: &[u8; 32], script_hash: &[u8; 32], data: &[u8]) -> Action {
+    Action::new_builder()
+        .script_info_hash(script_info_hash.pack())
+        .script_hash(script_hash.pack())
+        .data(data.pack())
+        .build()
+}
+
+pub fn create_message(actions: Vec<Action>) -> Message {
+    Message::new_builder()
+        .actions(ActionVec::new_builder().set(actions).build())
+        .build()
+}
+
+pub fn create_sighash_all(message: &Message, seal: &[u8]) -> SighashAll {
+    SighashAll::new_builder()
+        .message(message.clone())
+        .seal(seal.pack())
+        .build()
+}
+
+// The scripts an action can be for: lock and type scripts of inputs, and type
+// scripts of outputs.
+pub fn tx_script_hashes(tx: &ReprMockTransaction) -> Vec<[u8; 32]> {
+    let mut hashes = vec![];
+    for input in &tx.mock_info.inputs {
+        hashes.push(packed::Script::from(input.output.lock.clone()).calc_script_hash());
+        if let Some(type_) = &input.output.type_ {
+            hashes.push(packed::Script::from(type_.clone()).calc_script_hash());
+        }
+    }
+    for output in &tx.tx.outputs {
+        if let Some(type_) = &output.type_ {
+            hashes.push(packed::Script::from(type_.clone()).calc_script_hash());
+        }
+    }
+    hashes.into_iter().map(|hash| hash.unpack()).collect()
+}
+
+// The signing message of a SighashAll, see envelope.rs in
+// ckb-combine-lock-types. `sighash` is the message of generate_sighash_all,
+// with the whole SighashAll zeroed.
+pub fn generate_signing_message(
+    tx: &ReprMockTransaction,
+    message: &Message,
+    sighash: &[u8; 32],
+) -> Result<[u8; 32], anyhow::Error> {
+    if let Some(index) = find_unknown_action(&message.as_reader(), &tx_script_hashes(tx)) {
+        anyhow::bail!("action {} is not for a script in the transaction", index);
+    }
+    Ok(hash_signing_message(message, sighash))
+}
+
+pub fn hash_signing_message(message: &Message, sighash: &[u8; 32]) -> [u8; 32] {
+    let mut blake2b = Blake2bBuilder::new(32)
+        .personal(COBUILD_PERSONALIZATION)
+        .build();
+    update_signing_message(&message.as_reader(), sighash, |data| blake2b.update(data));
+    let mut result = [0u8; 32];
+    blake2b.finalize(&mut result);
+    result
+}
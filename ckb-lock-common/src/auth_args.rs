@@ -0,0 +1,127 @@
+// Layout of script args carrying a ckb-auth identity, e.g. child-script-example.
+//
+// Legacy layout, exactly 21 bytes. The ckb-auth entry is chosen by the script:
+// | 1 byte auth algorithm ID | 20 bytes pubkey hash |
+//
+// Version 1, exactly 56 bytes:
+// | 1 byte version = 1 | 1 byte auth algorithm ID | 20 bytes pubkey hash |
+// | 34 bytes entry |
+//
+// Version 2, for identities other than a 20 bytes pubkey hash, e.g. full
+// public keys or multisig scripts:
+// | 1 byte version = 2 | 1 byte auth algorithm ID | 34 bytes entry |
+// | identity, at least 1 byte, till the end of args |
+//
+// Version 3, same as version 2, but the witness starts with a sighash mode, see
+// generate_sighash.rs. The signature follows it.
+//
+// Version 4, same as version 2, but the witness is a SighashAll, a building
+// packet with the actions of the transaction, see envelope.rs. Its seal is the
+// signature.
+//
+// entry:
+// | 32 bytes ckb-auth code hash | 1 byte hash type | 1 byte entry category |
+// hash type: 0 data, 1 type, 2 data1
+// entry category: 0 exec, 1 dynamic linking, 2 spawn
+//
+// Unknown versions and lengths are rejected. Extra fields can only be added
+// with a new version.
+
+use crate::ckb_auth::{
+    AuthAlgorithmIdType, CkbAuthError, CkbAuthType, CkbEntryType, EntryCategoryType,
+};
+use ckb_std::ckb_types::core::ScriptHashType;
+use log::warn;
+
+pub const AUTH_ARGS_VERSION_1: u8 = 1;
+pub const AUTH_ARGS_VERSION_2: u8 = 2;
+pub const AUTH_ARGS_VERSION_3: u8 = 3;
+pub const AUTH_ARGS_VERSION_4: u8 = 4;
+const LEGACY_AUTH_ARGS_LEN: usize = 21;
+const PUBKEY_HASH_LEN: usize = 20;
+const ENTRY_LEN: usize = 34;
+
+// What the witness of the child script holds.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WitnessLayout {
+    Signature,
+    // a sighash mode, then the signature, since version 3
+    SighashMode,
+    // a SighashAll, since version 4
+    Envelope,
+}
+
+pub struct AuthArgs {
+    pub id: CkbAuthType,
+    // None for the legacy layout
+    pub entry: Option<CkbEntryType>,
+    pub witness_layout: WitnessLayout,
+}
+
+impl AuthArgs {
+    pub fn parse(args: &[u8]) -> Result<Self, CkbAuthError> {
+        if args.len() == LEGACY_AUTH_ARGS_LEN {
+            return Ok(Self {
+                id: CkbAuthType {
+                    algorithm_id: AuthAlgorithmIdType::try_from(args[0])?,
+                    identity: args[1..].to_vec(),
+                },
+                entry: None,
+                witness_layout: WitnessLayout::Signature,
+            });
+        }
+        if args.len() < 2 + ENTRY_LEN {
+            warn!("wrong auth args length: {}", args.len());
+            return Err(CkbAuthError::InvalidArgs);
+        }
+        let (entry, identity) = match args[0] {
+            AUTH_ARGS_VERSION_1 => {
+                if args.len() != 2 + PUBKEY_HASH_LEN + ENTRY_LEN {
+                    warn!("wrong auth args length: {}", args.len());
+                    return Err(CkbAuthError::InvalidArgs);
+                }
+                let (identity, entry) = args[2..].split_at(PUBKEY_HASH_LEN);
+                (entry, identity)
+            }
+            AUTH_ARGS_VERSION_2 | AUTH_ARGS_VERSION_3 | AUTH_ARGS_VERSION_4 => {
+                if args.len() == 2 + ENTRY_LEN {
+                    warn!("empty identity in auth args");
+                    return Err(CkbAuthError::InvalidArgs);
+                }
+                args[2..].split_at(ENTRY_LEN)
+            }
+            version => {
+                warn!("unknown auth args version: {}", version);
+                return Err(CkbAuthError::InvalidArgs);
+            }
+        };
+        Ok(Self {
+            id: CkbAuthType {
+                algorithm_id: AuthAlgorithmIdType::try_from(args[1])?,
+                identity: identity.to_vec(),
+            },
+            entry: Some(parse_entry(entry)?),
+            witness_layout: match args[0] {
+                AUTH_ARGS_VERSION_3 => WitnessLayout::SighashMode,
+                AUTH_ARGS_VERSION_4 => WitnessLayout::Envelope,
+                _ => WitnessLayout::Signature,
+            },
+        })
+    }
+}
+
+fn parse_entry(entry: &[u8]) -> Result<CkbEntryType, CkbAuthError> {
+    let hash_type = match entry[32] {
+        0 => ScriptHashType::Data,
+        1 => ScriptHashType::Type,
+        2 => ScriptHashType::Data1,
+        _ => return Err(CkbAuthError::InvalidArgs),
+    };
+    let entry_category =
+        EntryCategoryType::try_from(entry[33]).map_err(|_| CkbAuthError::InvalidArgs)?;
+    Ok(CkbEntryType {
+        code_hash: entry[..32].try_into().unwrap(),
+        hash_type,
+        entry_category,
+    })
+}
@@ -3,17 +3,17 @@ extern crate alloc;
 use alloc::ffi::CString;
 use alloc::ffi::NulError;
 use alloc::format;
+use alloc::vec::Vec;
 use ckb_std::{
     ckb_types::core::ScriptHashType,
-    dynamic_loading_c_impl::{CKBDLContext, Library, Symbol},
-    high_level::exec_cell,
+    dynamic_loading_c_impl::{CKBDLContext, Error as DLError, Library, Symbol},
+    high_level::{exec_cell, spawn_cell},
     syscalls::SysError,
 };
 use log::info;
 // use core::ffi::CStr;
 use alloc::collections::BTreeMap;
 use core::mem::size_of_val;
-use core::mem::transmute;
 use hex::encode;
 
 #[derive(Debug)]
@@ -23,8 +23,12 @@ pub enum CkbAuthError {
     LoadDLError,
     LoadDLFuncError,
     RunDLError,
+    RunSpawnError,
+    // no space left in the dynamic linking context of CKBDLLoader
+    DLMemoryNotEnough,
     ExecError(SysError),
     EncodeArgs,
+    InvalidArgs,
 }
 
 impl From<SysError> for CkbAuthError {
@@ -53,6 +57,13 @@ pub enum AuthAlgorithmIdType {
     Schnorr = 7,
     Rsa = 8,
     Iso97962 = 9,
+    Litecoin = 10,
+    Cardano = 11,
+    Monero = 12,
+    Solana = 13,
+    Ripple = 14,
+    // WebAuthn/passkey
+    Secp256r1 = 15,
     OwnerLock = 0xFC,
 }
 
@@ -65,25 +76,40 @@ impl Into<u8> for AuthAlgorithmIdType {
 impl TryFrom<u8> for AuthAlgorithmIdType {
     type Error = CkbAuthError;
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        if (value >= AuthAlgorithmIdType::Ckb.into()
-            && value <= AuthAlgorithmIdType::Iso97962.into())
-            || value == AuthAlgorithmIdType::OwnerLock.into()
-        {
-            Ok(unsafe { transmute(value) })
-        } else {
-            Err(CkbAuthError::UnknowAlgorithmID)
+        match value {
+            0 => Ok(Self::Ckb),
+            1 => Ok(Self::Ethereum),
+            2 => Ok(Self::Eos),
+            3 => Ok(Self::Tron),
+            4 => Ok(Self::Bitcoin),
+            5 => Ok(Self::Dogecoin),
+            6 => Ok(Self::CkbMultisig),
+            7 => Ok(Self::Schnorr),
+            8 => Ok(Self::Rsa),
+            9 => Ok(Self::Iso97962),
+            10 => Ok(Self::Litecoin),
+            11 => Ok(Self::Cardano),
+            12 => Ok(Self::Monero),
+            13 => Ok(Self::Solana),
+            14 => Ok(Self::Ripple),
+            15 => Ok(Self::Secp256r1),
+            0xFC => Ok(Self::OwnerLock),
+            _ => Err(CkbAuthError::UnknowAlgorithmID),
         }
     }
 }
 
 pub struct CkbAuthType {
     pub algorithm_id: AuthAlgorithmIdType,
-    pub pubkey_hash: [u8; 20],
+    // Passed to ckb-auth as is. It's a 20 bytes pubkey hash for most
+    // algorithms, but can be a full public key, a multisig script, etc.
+    pub identity: Vec<u8>,
 }
 
 pub enum EntryCategoryType {
     Exec = 0,
     DynamicLinking = 1,
+    Spawn = 2,
 }
 
 impl TryFrom<u8> for EntryCategoryType {
@@ -92,6 +118,7 @@ impl TryFrom<u8> for EntryCategoryType {
         match value {
             0 => Ok(Self::Exec),
             1 => Ok(Self::DynamicLinking),
+            2 => Ok(Self::Spawn),
             _ => Err(CkbAuthError::EncodeArgs),
         }
     }
@@ -112,15 +139,17 @@ pub fn ckb_auth(
     match entry.entry_category {
         EntryCategoryType::Exec => ckb_auth_exec(entry, id, signature, message),
         EntryCategoryType::DynamicLinking => ckb_auth_dl(entry, id, signature, message),
+        EntryCategoryType::Spawn => ckb_auth_spawn(entry, id, signature, message),
     }
 }
 
-fn ckb_auth_exec(
+// The only argv item of ckb-auth run by exec or spawn.
+fn encode_args(
     entry: &CkbEntryType,
     id: &CkbAuthType,
     signature: &[u8],
     message: &[u8; 32],
-) -> Result<(), CkbAuthError> {
+) -> Result<CString, CkbAuthError> {
     let args = CString::new(format!(
         "{}:{:02X?}:{:02X?}:{}:{}:{}",
         encode(&entry.code_hash),
@@ -128,15 +157,66 @@ fn ckb_auth_exec(
         id.algorithm_id.clone() as u8,
         encode(signature),
         encode(message),
-        encode(id.pubkey_hash)
+        encode(&id.identity)
     ))?;
+    Ok(args)
+}
+
+fn ckb_auth_exec(
+    entry: &CkbEntryType,
+    id: &CkbAuthType,
+    signature: &[u8],
+    message: &[u8; 32],
+) -> Result<(), CkbAuthError> {
+    let args = encode_args(entry, id, signature, message)?;
 
     // info!("args: {:?}", args);
     exec_cell(&entry.code_hash, entry.hash_type, &[args.as_c_str()])?;
     Ok(())
 }
 
-type DLContext = CKBDLContext<[u8; 512 * 1024]>;
+// in the unit of spawn (0.5 MB), the maximum
+const SPAWN_MEMORY_LIMIT: u64 = 8;
+
+// Unlike exec, it returns after ckb-auth exits, so the caller can go on with
+// more verifications.
+fn ckb_auth_spawn(
+    entry: &CkbEntryType,
+    id: &CkbAuthType,
+    signature: &[u8],
+    message: &[u8; 32],
+) -> Result<(), CkbAuthError> {
+    let args = encode_args(entry, id, signature, message)?;
+
+    let rc_code = spawn_cell(
+        &entry.code_hash,
+        entry.hash_type,
+        &[args.as_c_str()],
+        SPAWN_MEMORY_LIMIT,
+        &mut Vec::new(),
+    )?;
+    match rc_code {
+        0 => Ok(()),
+        _ => {
+            info!("run auth error({}) in spawn", rc_code);
+            Err(CkbAuthError::RunSpawnError)
+        }
+    }
+}
+
+// Size of the dynamic linking context shared by all libraries loaded by
+// CKBDLLoader. It's 512 KB by default, and can be changed by feature
+// "dl-context-256k" or "dl-context-1m".
+#[cfg(all(feature = "dl-context-256k", feature = "dl-context-1m"))]
+compile_error!("features \"dl-context-256k\" and \"dl-context-1m\" are exclusive");
+#[cfg(feature = "dl-context-256k")]
+pub const DL_CONTEXT_SIZE: usize = 256 * 1024;
+#[cfg(feature = "dl-context-1m")]
+pub const DL_CONTEXT_SIZE: usize = 1024 * 1024;
+#[cfg(not(any(feature = "dl-context-256k", feature = "dl-context-1m")))]
+pub const DL_CONTEXT_SIZE: usize = 512 * 1024;
+
+type DLContext = CKBDLContext<[u8; DL_CONTEXT_SIZE]>;
 type CkbAuthValidate = unsafe extern "C" fn(
     auth_algorithm_id: u8,
     signature: *const u8,
@@ -149,7 +229,9 @@ type CkbAuthValidate = unsafe extern "C" fn(
 
 const EXPORTED_FUNC_NAME: &str = "ckb_auth_validate";
 
-struct CKBDLLoader {
+// Libraries are never unloaded, so the symbols got from the loader stay valid
+// until the script exits.
+pub struct CKBDLLoader {
     pub context: DLContext,
     pub context_used: usize,
     pub loaded_lib: BTreeMap<[u8; 33], Library>,
@@ -177,6 +259,16 @@ impl CKBDLLoader {
         }
     }
 
+    // Size of the context in bytes, including the part used by loaded libraries.
+    pub fn capacity(&self) -> usize {
+        size_of_val(&self.context)
+    }
+
+    // Bytes left for libraries not loaded yet.
+    pub fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.context_used
+    }
+
     fn get_lib(
         &mut self,
         code_hash: &[u8; 32],
@@ -192,12 +284,18 @@ impl CKBDLLoader {
         };
 
         if !has_lib {
-            info!("loading library");
-            let size = size_of_val(&self.context);
+            info!(
+                "loading library, {} bytes remaining",
+                self.remaining_capacity()
+            );
+            let size = self.remaining_capacity();
             let lib = self
                 .context
                 .load_with_offset(code_hash, hash_type, self.context_used, size)
-                .map_err(|_| CkbAuthError::LoadDLError)?;
+                .map_err(|err| match err {
+                    DLError::MemoryNotEnough => CkbAuthError::DLMemoryNotEnough,
+                    _ => CkbAuthError::LoadDLError,
+                })?;
             self.context_used += lib.consumed_size();
             self.loaded_lib.insert(lib_key.clone(), lib);
         };
@@ -232,7 +330,7 @@ fn ckb_auth_dl(
         EXPORTED_FUNC_NAME,
     )?;
 
-    let mut pub_key = id.pubkey_hash.clone();
+    let mut identity = id.identity.clone();
     let rc_code = unsafe {
         func(
             id.algorithm_id.clone().into(),
@@ -240,8 +338,8 @@ fn ckb_auth_dl(
             signature.len() as u32,
             message.as_ptr(),
             message.len() as u32,
-            pub_key.as_mut_ptr(),
-            pub_key.len() as u32,
+            identity.as_mut_ptr(),
+            identity.len() as u32,
         )
     };
 
@@ -0,0 +1,130 @@
+// Layout of combine lock script args, also the first item of argv when it's
+// run by lock-wrapper.
+//
+// Legacy layouts, version 0:
+// | 32 bytes ChildScriptConfig hash |
+// | 1 byte flag = 0 | 32 bytes ChildScriptConfig hash |
+// | 1 byte flag = 1 | 32 bytes global registry ID | 32 bytes ChildScriptConfig hash |
+// The flagged ones are from lock-wrapper and the config cells of global
+// registry before version 1, they're read as the same config sources below.
+//
+// Version 1:
+// | 1 byte version = 1 | 1 byte flags | fields of the config source | user args |
+// flags bit 0-1, where ChildScriptConfig comes from:
+//     0: witness
+//         | 32 bytes ChildScriptConfig hash |
+//     1: global registry, see lock_wrapper.rs. Only as a lock script run directly
+//         | 32 bytes global registry ID | 32 bytes ChildScriptConfig hash |
+//     2: cell_dep by type hash, e.g. a Type ID cell whose data is the config
+//         | 32 bytes type script hash of the cell_dep | 32 bytes ChildScriptConfig hash |
+// flags bit 2: user args follow the fields. They're not covered by the
+//     ChildScriptConfig hash, so users sharing a config can have different
+//     args, e.g. pubkey hash. Child scripts refer to them by `args_slice` in
+//     ChildScriptConfigV2.
+// flags bit 3-7: reserved, must be 0
+//
+// Unknown versions, reserved flags and trailing bytes without flags bit 2 are
+// rejected. Extra fields can only be added with a new version. 65 bytes args
+// starting with 1 are always read as the legacy global registry layout, so
+// version 1 args can't have exactly 31 bytes of user args.
+
+use super::error::Error;
+use alloc::vec::Vec;
+use log::warn;
+
+pub const ARGS_VERSION: u8 = 1;
+const LEGACY_ARGS_LEN: usize = 32;
+const LEGACY_FLAG_WITNESS: u8 = 0;
+const LEGACY_FLAG_GLOBAL_REGISTRY: u8 = 1;
+const CONFIG_SOURCE_MASK: u8 = 0b11;
+const FLAG_USER_ARGS: u8 = 0b100;
+const CONFIG_SOURCE_WITNESS: u8 = 0;
+const CONFIG_SOURCE_GLOBAL_REGISTRY: u8 = 1;
+const CONFIG_SOURCE_CELL_DEP: u8 = 2;
+
+pub enum ConfigSource {
+    Witness {
+        config_hash: [u8; 32],
+    },
+    GlobalRegistry {
+        global_registry_id: [u8; 32],
+        config_hash: [u8; 32],
+    },
+    CellDep {
+        type_hash: [u8; 32],
+        config_hash: [u8; 32],
+    },
+}
+
+pub struct CombineLockArgs {
+    // 0 for the legacy layout
+    pub version: u8,
+    pub config_source: ConfigSource,
+    // empty for the legacy layout
+    pub user_args: Vec<u8>,
+}
+
+impl CombineLockArgs {
+    pub fn parse(args: &[u8]) -> Result<Self, Error> {
+        let legacy_config_source = match (args.len(), args.first()) {
+            (LEGACY_ARGS_LEN, _) => Some(ConfigSource::Witness {
+                config_hash: args.try_into().unwrap(),
+            }),
+            (33, Some(&LEGACY_FLAG_WITNESS)) => Some(ConfigSource::Witness {
+                config_hash: args[1..].try_into().unwrap(),
+            }),
+            (65, Some(&LEGACY_FLAG_GLOBAL_REGISTRY)) => Some(ConfigSource::GlobalRegistry {
+                global_registry_id: args[1..33].try_into().unwrap(),
+                config_hash: args[33..].try_into().unwrap(),
+            }),
+            _ => None,
+        };
+        if let Some(config_source) = legacy_config_source {
+            return Ok(Self {
+                version: 0,
+                config_source,
+                user_args: Vec::new(),
+            });
+        }
+        if args.len() < 2 {
+            return Err(Error::InvalidArgs);
+        }
+        if args[0] != ARGS_VERSION {
+            warn!("unknown args version: {}", args[0]);
+            return Err(Error::UnknownArgsVersion);
+        }
+        let flags = args[1];
+        if flags & !(CONFIG_SOURCE_MASK | FLAG_USER_ARGS) != 0 {
+            return Err(Error::InvalidArgs);
+        }
+        let fields_len = match flags & CONFIG_SOURCE_MASK {
+            CONFIG_SOURCE_WITNESS => 32,
+            CONFIG_SOURCE_GLOBAL_REGISTRY | CONFIG_SOURCE_CELL_DEP => 64,
+            _ => return Err(Error::InvalidArgs),
+        };
+        if args.len() < 2 + fields_len
+            || (flags & FLAG_USER_ARGS == 0 && args.len() != 2 + fields_len)
+        {
+            return Err(Error::InvalidArgs);
+        }
+        let (fields, user_args) = args[2..].split_at(fields_len);
+        let config_source = match flags & CONFIG_SOURCE_MASK {
+            CONFIG_SOURCE_WITNESS => ConfigSource::Witness {
+                config_hash: fields.try_into().unwrap(),
+            },
+            CONFIG_SOURCE_GLOBAL_REGISTRY => ConfigSource::GlobalRegistry {
+                global_registry_id: fields[..32].try_into().unwrap(),
+                config_hash: fields[32..].try_into().unwrap(),
+            },
+            _ => ConfigSource::CellDep {
+                type_hash: fields[..32].try_into().unwrap(),
+                config_hash: fields[32..].try_into().unwrap(),
+            },
+        };
+        Ok(Self {
+            version: ARGS_VERSION,
+            config_source,
+            user_args: user_args.to_vec(),
+        })
+    }
+}
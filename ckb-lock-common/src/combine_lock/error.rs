@@ -0,0 +1,144 @@
+use ckb_std::error::SysError;
+use log::warn;
+/// Error
+#[repr(i8)]
+pub enum Error {
+    IndexOutOfBound = 1,
+    ItemMissing,
+    LengthNotEnough,
+    Encoding,
+    // Add customized errors here...
+    WrongFormat = 80,
+    WrongHashType,
+    ChildScriptArrayIndexOutOfBounds,
+    CombineLockWitnessIndexOutOfBounds,
+    // not used: a failed child script is reported by ExitCode::ChildScriptFailed
+    UnlockFailed,
+    InnerWitnessIndexOutOfBounds,
+    ChildScriptHashMismatched,
+    // error reported from ckb_lock_common
+    // mainly from LockWrapper
+    CommonError,
+    // threshold in ChildScriptConfigV2 is larger than the number of child scripts,
+    // or it's missing
+    InvalidThreshold,
+    ThresholdNotReached,
+    InvalidChildScriptSelection,
+    // policy tree in ChildScriptConfigV2
+    InvalidPolicy,
+    PolicyNotSatisfied,
+    // since in ChildScriptConfigV2
+    InvalidSince,
+    SinceNotSatisfied,
+    // output capacity limit in ChildScriptConfigV2
+    InvalidCapacityLimit,
+    CapacityLimitExceeded,
+    // child script can't be loaded by dynamic linking, with "exec-fallback" feature
+    LoadChildScriptError,
+    // script args, see args.rs
+    UnknownArgsVersion,
+    InvalidArgs,
+    // no cell_dep with the type hash in args
+    ConfigCellDepNotFound,
+    // memory limit or cycles limit in ChildScriptConfigV2, see resource.rs
+    InvalidResourceLimit,
+    CyclesLimitExceeded,
+    // args slice in ChildScriptConfigV2, see template.rs
+    InvalidArgsSlice,
+    // no space left to load child scripts by dynamic linking, with
+    // "exec-fallback" feature
+    DLMemoryNotEnough,
+}
+
+/// Exit code of the combine lock
+pub enum ExitCode {
+    Error(Error),
+    // The child script at `position` (in running order) exits with `exit_code`.
+    ChildScriptFailed { position: usize, exit_code: i8 },
+}
+
+// A failed child script is reported in range [-128, -17]:
+// bit 7: always 1
+// bit 4-6: position of the child script in running order, 6 means 6 or later.
+//          7 is never used, so [-16, -1] are left for the combine lock itself,
+//          e.g. -1 of a panic.
+// bit 0-3: exit code of the child script, 0 means it's not in [1, 15], e.g.
+//          the negative exit code of a nested combine lock. The exact exit code
+//          is logged as "child script at position N exited with M".
+impl From<ExitCode> for i8 {
+    fn from(code: ExitCode) -> Self {
+        match code {
+            ExitCode::Error(err) => err as i8,
+            ExitCode::ChildScriptFailed {
+                position,
+                exit_code,
+            } => {
+                let position = position.min(6) as u8;
+                let exit_code = if (1..=15).contains(&exit_code) {
+                    exit_code as u8
+                } else {
+                    0
+                };
+                (0x80 | (position << 4) | exit_code) as i8
+            }
+        }
+    }
+}
+
+impl From<Error> for ExitCode {
+    fn from(err: Error) -> Self {
+        Self::Error(err)
+    }
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        use SysError::*;
+        match err {
+            IndexOutOfBound => Self::IndexOutOfBound,
+            ItemMissing => Self::ItemMissing,
+            LengthNotEnough(_) => Self::LengthNotEnough,
+            Encoding => Self::Encoding,
+            Unknown(err_code) => panic!("unexpected sys error {}", err_code),
+            _ => panic!("unexpected sys error"),
+        }
+    }
+}
+
+impl From<molecule::error::VerificationError> for Error {
+    fn from(err: molecule::error::VerificationError) -> Self {
+        warn!("An error reported from VerificationError: {:?}", err);
+        Self::WrongFormat
+    }
+}
+
+impl From<molecule2::Error> for Error {
+    fn from(err: molecule2::Error) -> Self {
+        warn!("An error reported from molecule2: {:?}", err);
+        Self::WrongFormat
+    }
+}
+
+impl From<hex::FromHexError> for Error {
+    fn from(err: hex::FromHexError) -> Self {
+        warn!("An error reported from FromHexError: {:?}", err);
+        Self::WrongFormat
+    }
+}
+
+impl From<crate::ckb_auth::CkbAuthError> for Error {
+    fn from(err: crate::ckb_auth::CkbAuthError) -> Self {
+        warn!("An error reported from dynamic linking: {:?}", err);
+        match err {
+            crate::ckb_auth::CkbAuthError::DLMemoryNotEnough => Self::DLMemoryNotEnough,
+            _ => Self::LoadChildScriptError,
+        }
+    }
+}
+
+impl From<crate::error::Error> for Error {
+    fn from(err: crate::error::Error) -> Self {
+        warn!("An error reported from ckb_lock_common: {:?}", err);
+        Self::CommonError
+    }
+}
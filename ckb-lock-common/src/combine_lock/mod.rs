@@ -0,0 +1,745 @@
+//! The core of combine lock: child scripts composed by ChildScriptConfig are
+//! run with the CombineLockWitness in witness. It's shared by the combine lock
+//! contract and the combine type contract.
+
+pub mod args;
+pub mod error;
+pub mod policy;
+pub mod resource;
+mod runner;
+pub mod since;
+pub mod template;
+
+use self::args::{CombineLockArgs, ConfigSource};
+use self::error::{Error, ExitCode};
+use self::resource::{parse_resource_limits, ResourceLimit};
+use self::since::check_since;
+use self::template::resolve_child_script_args;
+use crate::{
+    blake2b::hash,
+    cursor_arg::{CursorArg, WitnessField},
+    error::Error as CommonError,
+    generated::{
+        blockchain::WitnessArgs,
+        combine_lock::{CombineLockWitness, CombineLockWitnessV2},
+    },
+    lock_wrapper::{lock_wrapper_entry, LockWrapperResult},
+    parent_context::{argv_len, ParentContext, CONTEXT_VERSION},
+    sighash_cache::{generate_sighash_all_with_cache, SighashCache},
+    simple_cursor::{table_field_count, SimpleCursor, WitnessDataSource},
+    utils::{net_outflow_capacity, transaction_fee},
+};
+use alloc::ffi::CString;
+use alloc::{format, vec, vec::Vec};
+use ckb_combine_lock_types::combine_lock::{
+    ChildScriptConfig, ChildScriptConfigReader, ChildScriptConfigV2, ChildScriptConfigV2Reader,
+};
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, core::ScriptHashType, prelude::*},
+    env,
+    high_level::{
+        load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_script, load_script_hash,
+        QueryIter,
+    },
+};
+use core::ffi::CStr;
+use core::result::Result;
+use hex::{decode, encode};
+use log::{info, warn};
+use molecule2::Cursor;
+
+/// How the script runs.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ScriptType {
+    /// As a lock script. CombineLockWitness is in `lock` of WitnessArgs.
+    Lock,
+    /// As a type script. CombineLockWitness is in `input_type` of WitnessArgs
+    /// of the first group input, or in `output_type` of the first group output
+    /// if there is no group input, e.g. minting.
+    Type,
+}
+
+// Where CombineLockWitness is read from.
+#[derive(Clone, Copy)]
+struct WitnessLocation {
+    script_type: ScriptType,
+    // Source::GroupInput or Source::GroupOutput
+    source: Source,
+}
+
+impl WitnessLocation {
+    fn field(&self, witness_args: WitnessArgs) -> Option<Cursor> {
+        match (self.script_type, self.source) {
+            (ScriptType::Lock, _) => witness_args.lock(),
+            (ScriptType::Type, Source::GroupInput) => witness_args.input_type(),
+            (ScriptType::Type, _) => witness_args.output_type(),
+        }
+    }
+
+    fn witness_field(&self) -> WitnessField {
+        match (self.script_type, self.source) {
+            (ScriptType::Lock, _) => WitnessField::Lock,
+            (ScriptType::Type, Source::GroupInput) => WitnessField::InputType,
+            (ScriptType::Type, _) => WitnessField::OutputType,
+        }
+    }
+}
+
+fn parse_args() -> Result<Bytes, Error> {
+    let len = argv_len();
+    if len == 0 {
+        let script = load_script()?;
+        return Ok(script.args().unpack());
+    }
+    if len == 2 || len == 3 {
+        return Ok(Bytes::from(decode(env::argv()[0].to_bytes())?));
+    }
+    return Err(Error::WrongFormat);
+}
+
+fn parse_witness(location: WitnessLocation, group_index: usize) -> Result<Cursor, Error> {
+    let len = argv_len();
+    if len == 0 {
+        let data_source = WitnessDataSource::new(location.source, group_index);
+        let witness_args: WitnessArgs = data_source.as_cursor()?.into();
+        let lock = location.field(witness_args).ok_or(Error::WrongFormat)?;
+        return Ok(lock);
+    }
+    if len == 2 || len == 3 {
+        let arg = CursorArg::parse(env::argv()[1].to_bytes(), location.source, group_index)
+            .map_err(|_| Error::WrongFormat)?;
+        // It can't point into a witness out of the script group.
+        if arg.source() != location.source || arg.witness_index as usize != group_index {
+            warn!("cursor in argv points into another witness");
+            return Err(Error::WrongFormat);
+        }
+        return arg.load().map_err(|_| Error::WrongFormat);
+    }
+    return Err(Error::WrongFormat);
+}
+
+fn parse_script_config(witness: &Cursor) -> Result<Bytes, Error> {
+    if argv_len() == 3 {
+        let script_config = Bytes::from(decode(env::argv()[2].to_bytes())?);
+        return Ok(script_config);
+    }
+    let combine_lock_witness: CombineLockWitness = witness.clone().into();
+    let script_config = combine_lock_witness
+        .script_config()
+        .ok_or(Error::WrongFormat)?;
+    let bytes: Vec<u8> = script_config
+        .cursor
+        .try_into()
+        .map_err(|_| Error::WrongFormat)?;
+    Ok(bytes.into())
+}
+
+// Loads ChildScriptConfig from where script args says, and checks it against
+// the hash in args.
+fn load_script_config(
+    script_type: ScriptType,
+    args: &CombineLockArgs,
+    witness: &Cursor,
+) -> Result<Bytes, Error> {
+    match args.config_source {
+        ConfigSource::Witness { config_hash } => {
+            let script_config = parse_script_config(witness)?;
+            if hash(&script_config) != config_hash {
+                return Err(Error::ChildScriptHashMismatched);
+            }
+            Ok(script_config)
+        }
+        ConfigSource::CellDep {
+            type_hash,
+            config_hash,
+        } => load_script_config_from_cell_dep(&type_hash, &config_hash),
+        ConfigSource::GlobalRegistry {
+            global_registry_id,
+            config_hash,
+        } => load_script_config_from_global_registry(
+            script_type,
+            witness,
+            &global_registry_id,
+            &config_hash,
+        ),
+    }
+}
+
+// The config cell in global registry shares the same lock script code with
+// the current one, so it's only supported as a lock script run directly, not
+// by lock-wrapper. Without the config in config cell, it's in witness and
+// checked against the hash in args, as ConfigSource::Witness.
+fn load_script_config_from_global_registry(
+    script_type: ScriptType,
+    witness: &Cursor,
+    global_registry_id: &[u8; 32],
+    config_hash: &[u8; 32],
+) -> Result<Bytes, Error> {
+    if script_type != ScriptType::Lock || argv_len() != 0 {
+        warn!("global registry is only supported by combine lock run directly");
+        return Err(Error::InvalidArgs);
+    }
+    match lock_wrapper_entry(global_registry_id, config_hash)? {
+        LockWrapperResult::ChildScriptConfig(script_config) => Ok(script_config.into()),
+        LockWrapperResult::ChildScriptConfigHash(config_hash) => {
+            let script_config = parse_script_config(witness)?;
+            if hash(&script_config) != config_hash {
+                return Err(Error::ChildScriptHashMismatched);
+            }
+            Ok(script_config)
+        }
+    }
+}
+
+// The config is the whole data of a cell_dep with `type_hash`, so many cells
+// can share one config cell. It's still checked against the hash in args, the
+// first matched one is used when there are several such cell_deps.
+fn load_script_config_from_cell_dep(
+    type_hash: &[u8; 32],
+    config_hash: &[u8; 32],
+) -> Result<Bytes, Error> {
+    let mut found = false;
+    for (index, hash_opt) in QueryIter::new(load_cell_type_hash, Source::CellDep).enumerate() {
+        if hash_opt.as_ref() != Some(type_hash) {
+            continue;
+        }
+        found = true;
+        let data = load_cell_data(index, Source::CellDep)?;
+        if &hash(&data) == config_hash {
+            return Ok(data.into());
+        }
+    }
+    if found {
+        warn!("the config in cell_dep doesn't match the hash in args");
+        Err(Error::ChildScriptHashMismatched)
+    } else {
+        Err(Error::ConfigCellDepNotFound)
+    }
+}
+
+// A CombineLockWitness in the script group and the inputs it unlocks.
+struct GroupWitness {
+    // index in the source of WitnessLocation
+    index: usize,
+    // the CombineLockWitness
+    lock: Cursor,
+    // indexes in Source::Input of the inputs unlocked by it, whose since are
+    // checked
+    inputs: Vec<usize>,
+}
+
+// The first witness in the script group is mandatory and unlocks all inputs
+// which don't have their own. When combine lock runs as the lock script
+// directly, any other group input can have its own CombineLockWitness in
+// `lock` of its WitnessArgs. It's verified independently and unlocks only that
+// input. A missing or empty witness, or one without `lock`, is left to the
+// first one. With lock-wrapper, only the first witness is used.
+//
+// As a type script, only the first witness is used. Without group input, it
+// covers all inputs of the transaction.
+fn load_group_witnesses(
+    location: WitnessLocation,
+    input_indexes: &[usize],
+) -> Result<Vec<GroupWitness>, Error> {
+    let lock = parse_witness(location, 0)?;
+    // The inputs are the group inputs only, even if there are none, e.g.
+    // minting. Then a `since` can't be met, see since.rs.
+    if location.script_type == ScriptType::Type {
+        return Ok(vec![GroupWitness {
+            index: 0,
+            lock,
+            inputs: input_indexes.to_vec(),
+        }]);
+    }
+    let mut first = GroupWitness {
+        index: 0,
+        lock,
+        inputs: vec![input_indexes[0]],
+    };
+    let mut others = Vec::new();
+    for (index, &input_index) in input_indexes.iter().enumerate().skip(1) {
+        match load_own_witness(index)? {
+            Some(lock) => others.push(GroupWitness {
+                index,
+                lock,
+                inputs: vec![input_index],
+            }),
+            None => first.inputs.push(input_index),
+        }
+    }
+    others.insert(0, first);
+    Ok(others)
+}
+
+fn load_own_witness(group_index: usize) -> Result<Option<Cursor>, Error> {
+    if argv_len() != 0 {
+        return Ok(None);
+    }
+    let cursor = match WitnessDataSource::new(Source::GroupInput, group_index).as_cursor() {
+        Ok(cursor) => cursor,
+        Err(CommonError::IndexOutOfBound) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    if cursor.size == 0 {
+        return Ok(None);
+    }
+    let witness_args: WitnessArgs = cursor.into();
+    Ok(witness_args.lock())
+}
+
+// Returns the positions chosen by `CombineLockWitnessV2.selection`. None means
+// all child scripts in the entry are chosen, which is also the case for the
+// legacy CombineLockWitness.
+fn parse_selection(witness: &Cursor) -> Result<Option<Vec<u8>>, Error> {
+    if table_field_count(witness)? < 4 {
+        return Ok(None);
+    }
+    let witness: CombineLockWitnessV2 = witness.clone().into();
+    let selection: Vec<u8> = witness.selection().try_into()?;
+    if selection.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(selection))
+    }
+}
+
+// Returns the index in `inner_witness` of every chosen child script, from
+// `CombineLockWitnessV2.inner_witness_index`. Without it, they're mapped one
+// by one.
+fn parse_inner_witness_index(
+    witness: &Cursor,
+    child_script_count: usize,
+    inner_witness_count: usize,
+) -> Result<Vec<usize>, Error> {
+    let indexes: Vec<u8> = if table_field_count(witness)? < 5 {
+        Vec::new()
+    } else {
+        let witness: CombineLockWitnessV2 = witness.clone().into();
+        witness.inner_witness_index().try_into()?
+    };
+    let indexes: Vec<usize> = if indexes.is_empty() {
+        (0..child_script_count).collect()
+    } else if indexes.len() == child_script_count {
+        indexes.into_iter().map(|i| i as usize).collect()
+    } else {
+        return Err(Error::InnerWitnessIndexOutOfBounds);
+    };
+    if indexes.iter().any(|&i| i >= inner_witness_count) {
+        return Err(Error::InnerWitnessIndexOutOfBounds);
+    }
+    Ok(indexes)
+}
+
+// The legacy ChildScriptConfig is also accepted: it's the same as a
+// ChildScriptConfigV2 without threshold and policy tree.
+// Capacity limits are per entry of `index`, which isn't used by a policy tree,
+// so a policy tree with them is rejected instead of ignoring them.
+fn parse_child_script_config(
+    script_config: Bytes,
+) -> Result<(ChildScriptConfig, Option<ChildScriptConfigV2>), Error> {
+    if ChildScriptConfigReader::from_compatible_slice(&script_config)?.has_extra_fields() {
+        ChildScriptConfigV2Reader::verify(&script_config, false)?;
+        let config = ChildScriptConfigV2::new_unchecked(script_config);
+        if !config.policy().is_empty()
+            && (!config.output_capacity_limit().is_empty() || !config.fee_limit().is_empty())
+        {
+            return Err(Error::InvalidCapacityLimit);
+        }
+        Ok((config.clone().into(), Some(config)))
+    } else {
+        ChildScriptConfigReader::verify(&script_config, false)?;
+        Ok((ChildScriptConfig::new_unchecked(script_config), None))
+    }
+}
+
+// Returns positions of the child scripts in the entry to be run.
+fn select_child_scripts(
+    len: usize,
+    threshold: usize,
+    selection: Option<Vec<u8>>,
+) -> Result<Vec<usize>, Error> {
+    let threshold = if threshold == 0 { len } else { threshold };
+    if threshold > len {
+        return Err(Error::InvalidThreshold);
+    }
+    let selection = match selection {
+        Some(selection) => selection,
+        None => return Ok((0..len).collect()),
+    };
+    let mut result: Vec<usize> = Vec::with_capacity(selection.len());
+    for position in selection {
+        let position = position as usize;
+        if position >= len || result.last().map_or(false, |last| *last >= position) {
+            return Err(Error::InvalidChildScriptSelection);
+        }
+        result.push(position);
+    }
+    if result.len() < threshold {
+        return Err(Error::ThresholdNotReached);
+    }
+    Ok(result)
+}
+
+// The capacity which can leave the lock: `output` plus the fee, up to `fee`.
+#[derive(Clone, Copy)]
+struct CapacityLimit {
+    output: u64,
+    fee: u64,
+}
+
+// What the witness chooses to unlock with: an entry of `index`, or a policy tree.
+struct UnlockPath {
+    // indexes of child scripts in `array` to be run
+    child_script_indexes: Vec<usize>,
+    capacity_limit: Option<CapacityLimit>,
+}
+
+fn resolve_unlock_path(
+    child_script_config: &ChildScriptConfig,
+    child_script_config_v2: &Option<ChildScriptConfigV2>,
+    witness_index: usize,
+    selection: Option<Vec<u8>>,
+    inputs: &[usize],
+) -> Result<UnlockPath, Error> {
+    let config = match child_script_config_v2 {
+        Some(config) => config,
+        None => {
+            let child_script_vec = child_script_config
+                .index()
+                .get(witness_index)
+                .ok_or(Error::CombineLockWitnessIndexOutOfBounds)?;
+            let child_script_indexes = select_child_scripts(child_script_vec.len(), 0, selection)?
+                .into_iter()
+                .map(|position| u8::from(child_script_vec.get(position).unwrap()) as usize)
+                .collect();
+            return Ok(UnlockPath {
+                child_script_indexes,
+                capacity_limit: None,
+            });
+        }
+    };
+    if !config.policy().is_empty() {
+        let child_script_indexes = policy::evaluate(
+            &config.policy(),
+            &config.since(),
+            witness_index,
+            &selection.unwrap_or_default(),
+            inputs,
+        )?;
+        return Ok(UnlockPath {
+            child_script_indexes,
+            capacity_limit: None,
+        });
+    }
+
+    let child_script_vec = config
+        .index()
+        .get(witness_index)
+        .ok_or(Error::CombineLockWitnessIndexOutOfBounds)?;
+    let since_vec = config.since();
+    if !since_vec.is_empty() {
+        check_since(
+            since_vec
+                .get(witness_index)
+                .ok_or(Error::InvalidSince)?
+                .unpack(),
+            inputs,
+        )?;
+    }
+    let limit_vec = config.output_capacity_limit();
+    let fee_limit_vec = config.fee_limit();
+    let capacity_limit = if limit_vec.is_empty() {
+        if !fee_limit_vec.is_empty() {
+            return Err(Error::InvalidCapacityLimit);
+        }
+        None
+    } else {
+        let fee = if fee_limit_vec.is_empty() {
+            0
+        } else {
+            fee_limit_vec
+                .get(witness_index)
+                .ok_or(Error::InvalidCapacityLimit)?
+                .unpack()
+        };
+        Some(CapacityLimit {
+            output: limit_vec
+                .get(witness_index)
+                .ok_or(Error::InvalidCapacityLimit)?
+                .unpack(),
+            fee,
+        })
+    };
+    let threshold = u8::from(
+        config
+            .threshold()
+            .get(witness_index)
+            .ok_or(Error::InvalidThreshold)?,
+    ) as usize;
+    let child_script_indexes = select_child_scripts(child_script_vec.len(), threshold, selection)?
+        .into_iter()
+        .map(|position| u8::from(child_script_vec.get(position).unwrap()) as usize)
+        .collect();
+    Ok(UnlockPath {
+        child_script_indexes,
+        capacity_limit,
+    })
+}
+
+// The limit is only for lock scripts. The fee of the transaction is allowed on
+// top of `output`, up to `fee`, so a lock limited to 0 can still pay the fee.
+fn check_capacity_limit(
+    script_type: ScriptType,
+    limit: Option<CapacityLimit>,
+) -> Result<(), Error> {
+    if limit.is_some() && script_type == ScriptType::Type {
+        return Err(Error::InvalidCapacityLimit);
+    }
+    if let Some(limit) = limit {
+        if limit.output != u64::MAX {
+            let lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+            let capacity = net_outflow_capacity(&lock_hash)?;
+            let fee = transaction_fee().min(limit.fee);
+            if capacity > limit.output.saturating_add(fee) {
+                warn!(
+                    "capacity leaving the lock: {}, limit: {}, fee allowed: {}",
+                    capacity, limit.output, fee
+                );
+                return Err(Error::CapacityLimitExceeded);
+            }
+        }
+    }
+    Ok(())
+}
+
+// indexes in Source::Input of the inputs in the script group
+fn group_input_indexes(script_type: ScriptType) -> Result<Vec<usize>, Error> {
+    let script_hash = load_script_hash()?;
+    let indexes = match script_type {
+        ScriptType::Lock => QueryIter::new(load_cell_lock_hash, Source::Input)
+            .enumerate()
+            .filter(|(_, lock_hash)| lock_hash == &script_hash)
+            .map(|(i, _)| i)
+            .collect(),
+        ScriptType::Type => QueryIter::new(load_cell_type_hash, Source::Input)
+            .enumerate()
+            .filter(|(_, type_hash)| type_hash == &Some(script_hash))
+            .map(|(i, _)| i)
+            .collect(),
+    };
+    Ok(indexes)
+}
+
+// The parts of ParentContext shared by all group witnesses.
+struct SharedContext {
+    message: [u8; 32],
+    masks: Vec<(usize, SimpleCursor)>,
+    sighash_cache: SighashCache,
+}
+
+// The message shared by all child scripts. It's signed with all
+// `inner_witness` items in every CombineLockWitness of the script group
+// zeroed, so the child scripts can sign the same message. The zeroed ranges
+// are returned too, with the witness digests computed in the same pass.
+fn shared_context(
+    location: WitnessLocation,
+    group_witnesses: &[GroupWitness],
+) -> Result<SharedContext, Error> {
+    let mut masks = Vec::new();
+    for group_witness in group_witnesses {
+        let witness: CombineLockWitness = group_witness.lock.clone().into();
+        let inner_witness = witness.inner_witness();
+        for i in 0..inner_witness.len() {
+            let cursor = SimpleCursor::new_from_cursor(&inner_witness.get(i));
+            masks.push((group_witness.index, cursor));
+        }
+    }
+    let (message, sighash_cache) = generate_sighash_all_with_cache(location.source, &masks)?;
+    Ok(SharedContext {
+        message,
+        masks,
+        sighash_cache,
+    })
+}
+
+fn verify_group_witness(
+    location: WitnessLocation,
+    group_witness: &GroupWitness,
+    child_script_config: &ChildScriptConfig,
+    child_script_config_v2: &Option<ChildScriptConfigV2>,
+    child_script_args: &[Bytes],
+    resource_limits: &[ResourceLimit],
+    shared_context: &Option<SharedContext>,
+    is_last_witness: bool,
+) -> Result<(), ExitCode> {
+    let selection = parse_selection(&group_witness.lock)?;
+
+    let witness: CombineLockWitness = group_witness.lock.clone().into();
+    let witness_index = witness.index() as usize;
+    let inner_witness = witness.inner_witness();
+
+    let unlock_path = resolve_unlock_path(
+        child_script_config,
+        child_script_config_v2,
+        witness_index,
+        selection,
+        &group_witness.inputs,
+    )?;
+    let child_script_array = child_script_config.array();
+    let child_script_count = unlock_path.child_script_indexes.len();
+    let inner_witness_indexes =
+        parse_inner_witness_index(&group_witness.lock, child_script_count, inner_witness.len())?;
+    let mut context = shared_context.as_ref().map(|shared| ParentContext {
+        version: CONTEXT_VERSION,
+        message: shared.message,
+        witness_index: witness_index as u16,
+        position: 0,
+        group_input_indexes: group_witness.inputs.iter().map(|&i| i as u32).collect(),
+        group_witness_index: group_witness.index as u32,
+        group_witness_source: location.source,
+        masks: shared.masks.clone(),
+        sighash_cache: Some(shared.sighash_cache.clone()),
+    });
+    for (i, &child_script_index) in unlock_path.child_script_indexes.iter().enumerate() {
+        let child_script = child_script_array
+            .get(child_script_index)
+            .ok_or(Error::ChildScriptArrayIndexOutOfBounds)?;
+        let child_script_args = encode(child_script_args[child_script_index].as_ref());
+        let child_script_inner_witness = inner_witness.get(inner_witness_indexes[i]);
+        // Legacy args (no shared context) keep the legacy `offset:size` text
+        // for child scripts written before CursorArg. It can only point into
+        // the first witness in Source::GroupInput, the one such child scripts
+        // read. The other witnesses only exist with multiple witnesses or in
+        // ckb-combine-type, and get CursorArg.
+        let legacy_cursor = shared_context.is_none()
+            && location.source == Source::GroupInput
+            && group_witness.index == 0;
+        let witness_cursor = if legacy_cursor {
+            format!(
+                "{}",
+                SimpleCursor::new_from_cursor(&child_script_inner_witness)
+            )
+        } else {
+            CursorArg::new(
+                location.source,
+                group_witness.index,
+                &child_script_inner_witness,
+                Some(location.witness_field()),
+            )
+            .map_err(Error::from)?
+            .to_arg()
+        };
+        info!(
+            "run child script code_hash = {}, hash_type = {}, index = {}, child_script_args = {:?}, witness = {}",
+            child_script.code_hash(),
+            child_script.hash_type(),
+            i,
+            child_script_args,
+            witness_cursor
+        );
+
+        let code_hash: [u8; 32] = child_script.code_hash().as_slice().try_into().unwrap();
+        let hash_type = match u8::from(child_script.hash_type()) {
+            0 => ScriptHashType::Data,
+            1 => ScriptHashType::Type,
+            2 => ScriptHashType::Data1,
+            _ => return Err(Error::WrongHashType.into()),
+        };
+        let is_last = is_last_witness && i + 1 == child_script_count;
+        // exec doesn't return, so the outputs are checked before it.
+        if cfg!(feature = "exec-fallback") && is_last {
+            check_capacity_limit(location.script_type, unlock_path.capacity_limit)?;
+        }
+        let mut argv = vec![
+            CString::new(child_script_args.as_str()).unwrap(),
+            CString::new(witness_cursor).unwrap(),
+        ];
+        if let Some(context) = context.as_mut() {
+            context.position = i as u8;
+            argv.push(CString::new(context.to_arg()).unwrap());
+        }
+        let argv: Vec<&CStr> = argv.iter().map(|arg| arg.as_c_str()).collect();
+        let exit_code = runner::run_child_script(
+            &code_hash,
+            hash_type,
+            &argv,
+            &resource_limits[child_script_index],
+            is_last,
+        )?;
+        if exit_code != 0 {
+            warn!("child script at position {} exited with {}", i, exit_code);
+            return Err(ExitCode::ChildScriptFailed {
+                position: i,
+                exit_code,
+            });
+        }
+    }
+
+    check_capacity_limit(location.script_type, unlock_path.capacity_limit)?;
+    Ok(())
+}
+
+/// Runs the child scripts chosen by CombineLockWitness, as a lock script or a
+/// type script.
+pub fn combine_lock_entry(script_type: ScriptType) -> Result<(), ExitCode> {
+    // We have following molecule definition of CombineLockWitness:
+    // table CombineLockWitness {
+    // index: Uint16,
+    // inner_witness: BytesVec,
+    // script_config: ChildScriptConfigOpt,
+    // }
+    // The `index` and `inner_witness` must be from local witness
+    // The `script_config` can be from local witness or config cell.
+    // CombineLockWitnessV2 appends `selection` and `inner_witness_index` to it.
+    // The `script_config` is only read from the first witness in the script
+    // group, it can be omitted in others.
+    let input_indexes = group_input_indexes(script_type)?;
+    let location = WitnessLocation {
+        script_type,
+        source: if script_type == ScriptType::Type && input_indexes.is_empty() {
+            Source::GroupOutput
+        } else {
+            Source::GroupInput
+        },
+    };
+    let group_witnesses = load_group_witnesses(location, &input_indexes)?;
+
+    let args = CombineLockArgs::parse(&parse_args()?)?;
+    let script_config = load_script_config(script_type, &args, &group_witnesses[0].lock)?;
+    let (child_script_config, child_script_config_v2) = parse_child_script_config(script_config)?;
+    let child_script_args = resolve_child_script_args(
+        &child_script_config,
+        &child_script_config_v2,
+        &args.user_args,
+    )?;
+    let resource_limits =
+        parse_resource_limits(&child_script_config_v2, child_script_config.array().len())?;
+
+    // Child scripts of the legacy args get the same argv as before ParentContext
+    // was added, see parent_context.rs.
+    let shared_context = if args.version >= 1 {
+        Some(shared_context(location, &group_witnesses)?)
+    } else {
+        None
+    };
+    for (i, group_witness) in group_witnesses.iter().enumerate() {
+        verify_group_witness(
+            location,
+            group_witness,
+            &child_script_config,
+            &child_script_config_v2,
+            &child_script_args,
+            &resource_limits,
+            &shared_context,
+            i + 1 == group_witnesses.len(),
+        )
+        .map_err(|err| {
+            // The exit code has no room for it, see decode-exit-code in
+            // ckb-debugger-tests.
+            warn!("group witness {} failed", i);
+            err
+        })?;
+    }
+    Ok(())
+}
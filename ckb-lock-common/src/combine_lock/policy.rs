@@ -0,0 +1,129 @@
+use super::error::Error;
+use super::since::check_since;
+use alloc::vec;
+use alloc::vec::Vec;
+use ckb_combine_lock_types::combine_lock::{PolicyNode, PolicyNodeVec, SinceVec};
+use ckb_std::ckb_types::prelude::*;
+
+const KIND_LEAF: u8 = 0;
+const KIND_AND: u8 = 1;
+const KIND_OR: u8 = 2;
+const KIND_THRESHOLD: u8 = 3;
+const KIND_SINCE: u8 = 4;
+
+fn children(node: &PolicyNode) -> Vec<usize> {
+    node.children()
+        .into_iter()
+        .map(|c| u8::from(c) as usize)
+        .collect()
+}
+
+// Evaluates the policy tree rooted at `root`, with the leaf nodes in
+// `selection` as satisfied. A since node is satisfied when `since` of the
+// inputs at `inputs` in Source::Input meets the one it refers to in `since_vec`. On
+// success, returns indexes of child scripts (in `ChildScriptConfig.array`)
+// referenced by the selected leaf nodes, in the same order as `selection`. All
+// of them must succeed later. Leaf nodes outside the tree rooted at `root`
+// can't be selected, they would run child scripts not required by the policy.
+pub fn evaluate(
+    policy: &PolicyNodeVec,
+    since_vec: &SinceVec,
+    root: usize,
+    selection: &[u8],
+    inputs: &[usize],
+) -> Result<Vec<usize>, Error> {
+    let len = policy.len();
+    if root >= len {
+        return Err(Error::CombineLockWitnessIndexOutOfBounds);
+    }
+    // children always have larger indexes than their parent, so a forward
+    // iteration visits parents first. The indexes of children are checked in
+    // the evaluation below.
+    let mut in_tree = vec![false; len];
+    in_tree[root] = true;
+    for i in root..len {
+        let node = policy.get(i).unwrap();
+        let kind = u8::from(node.kind());
+        if !in_tree[i] || kind == KIND_LEAF || kind == KIND_SINCE {
+            continue;
+        }
+        for c in children(&node) {
+            if c > i && c < len {
+                in_tree[c] = true;
+            }
+        }
+    }
+
+    let mut child_script_indexes = Vec::with_capacity(selection.len());
+    for (i, &node_index) in selection.iter().enumerate() {
+        if i > 0 && selection[i - 1] >= node_index {
+            return Err(Error::InvalidChildScriptSelection);
+        }
+        let node = policy
+            .get(node_index as usize)
+            .ok_or(Error::InvalidChildScriptSelection)?;
+        if u8::from(node.kind()) != KIND_LEAF || !in_tree[node_index as usize] {
+            return Err(Error::InvalidChildScriptSelection);
+        }
+        let children = children(&node);
+        if children.len() != 1 {
+            return Err(Error::InvalidPolicy);
+        }
+        child_script_indexes.push(children[0]);
+    }
+
+    // children always have larger indexes than their parent, so a reverse
+    // iteration visits them first.
+    let mut satisfied = vec![false; len];
+    for i in (root..len).rev() {
+        let node = policy.get(i).unwrap();
+        let children = children(&node);
+        let kind = u8::from(node.kind());
+        if kind == KIND_LEAF {
+            if children.len() != 1 {
+                return Err(Error::InvalidPolicy);
+            }
+            satisfied[i] = selection.binary_search(&(i as u8)).is_ok();
+            continue;
+        }
+        if kind == KIND_SINCE {
+            if children.len() != 1 {
+                return Err(Error::InvalidPolicy);
+            }
+            let since: u64 = since_vec
+                .get(children[0])
+                .ok_or(Error::InvalidSince)?
+                .unpack();
+            satisfied[i] = match check_since(since, inputs) {
+                Ok(_) => true,
+                Err(Error::SinceNotSatisfied) => false,
+                Err(err) => return Err(err),
+            };
+            continue;
+        }
+        if children.is_empty() || children.iter().any(|&c| c <= i || c >= len) {
+            return Err(Error::InvalidPolicy);
+        }
+        let count = children.iter().filter(|&&c| satisfied[c]).count();
+        satisfied[i] = match kind {
+            KIND_AND => count == children.len(),
+            KIND_OR => count > 0,
+            KIND_THRESHOLD => {
+                // 0 means all of them, same as ThresholdVec
+                let threshold = match u8::from(node.threshold()) as usize {
+                    0 => children.len(),
+                    threshold => threshold,
+                };
+                if threshold > children.len() {
+                    return Err(Error::InvalidThreshold);
+                }
+                count >= threshold
+            }
+            _ => return Err(Error::InvalidPolicy),
+        };
+    }
+    if !satisfied[root] {
+        return Err(Error::PolicyNotSatisfied);
+    }
+    Ok(child_script_indexes)
+}
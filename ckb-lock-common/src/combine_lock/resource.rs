@@ -0,0 +1,80 @@
+use super::error::Error;
+use alloc::vec;
+use alloc::vec::Vec;
+use ckb_combine_lock_types::combine_lock::ChildScriptConfigV2;
+use ckb_std::ckb_types::prelude::*;
+use log::warn;
+
+// The ceilings of resource limits in ChildScriptConfigV2. The memory limit is
+// in the unit of spawn (0.5 MB), and a spawned child script can't use more
+// than 8. The cycles limit is the default `max_tx_verify_cycles` of CKB.
+pub const MAX_MEMORY_LIMIT: u8 = 8;
+pub const MAX_CYCLES_LIMIT: u64 = 70_000_000;
+
+// Resource limits of a child script in `array`.
+#[derive(Clone, Copy)]
+pub struct ResourceLimit {
+    pub memory_limit: u64,
+    // None means no limit
+    pub cycles_limit: Option<u64>,
+}
+
+impl Default for ResourceLimit {
+    fn default() -> Self {
+        Self {
+            memory_limit: MAX_MEMORY_LIMIT as u64,
+            cycles_limit: None,
+        }
+    }
+}
+
+// Returns one ResourceLimit per child script in `array`. An empty vector in
+// config means the default for all child scripts, otherwise its length must
+// be the same as `array`. The legacy ChildScriptConfig has no limits.
+pub fn parse_resource_limits(
+    config: &Option<ChildScriptConfigV2>,
+    array_len: usize,
+) -> Result<Vec<ResourceLimit>, Error> {
+    let mut limits = vec![ResourceLimit::default(); array_len];
+    let config = match config {
+        Some(config) => config,
+        None => return Ok(limits),
+    };
+    let memory_limit = config.memory_limit();
+    if !memory_limit.is_empty() {
+        if memory_limit.len() != array_len {
+            return Err(Error::InvalidResourceLimit);
+        }
+        for (limit, memory_limit) in limits.iter_mut().zip(memory_limit.into_iter()) {
+            let memory_limit = match u8::from(memory_limit) {
+                0 => MAX_MEMORY_LIMIT,
+                m if m > MAX_MEMORY_LIMIT => {
+                    warn!("memory limit {} is larger than {}", m, MAX_MEMORY_LIMIT);
+                    return Err(Error::InvalidResourceLimit);
+                }
+                m => m,
+            };
+            limit.memory_limit = memory_limit as u64;
+        }
+    }
+    let cycles_limit = config.cycles_limit();
+    if !cycles_limit.is_empty() {
+        if cycles_limit.len() != array_len {
+            return Err(Error::InvalidResourceLimit);
+        }
+        for (limit, cycles_limit) in limits.iter_mut().zip(cycles_limit.into_iter()) {
+            let cycles_limit: u64 = cycles_limit.unpack();
+            if cycles_limit > MAX_CYCLES_LIMIT {
+                warn!(
+                    "cycles limit {} is larger than {}",
+                    cycles_limit, MAX_CYCLES_LIMIT
+                );
+                return Err(Error::InvalidResourceLimit);
+            }
+            if cycles_limit != 0 {
+                limit.cycles_limit = Some(cycles_limit);
+            }
+        }
+    }
+    Ok(limits)
+}
@@ -0,0 +1,104 @@
+use super::error::Error;
+use super::resource::ResourceLimit;
+use ckb_std::{ckb_types::core::ScriptHashType, syscalls::current_cycles};
+use core::ffi::CStr;
+use log::warn;
+
+#[cfg(not(feature = "exec-fallback"))]
+use {alloc::vec::Vec, ckb_std::high_level::spawn_cell};
+
+#[cfg(feature = "exec-fallback")]
+use {
+    crate::ckb_auth::CKBDLLoader,
+    alloc::vec::Vec,
+    ckb_std::{dynamic_loading_c_impl::Symbol, high_level::exec_cell},
+    core::ffi::c_char,
+};
+
+// Child scripts run by dynamic linking are built as shared libraries exporting
+// this function. It takes the same argv as the one passed by spawn or exec.
+#[cfg(feature = "exec-fallback")]
+type ChildScriptEntry = unsafe extern "C" fn(argc: i32, argv: *const *const c_char) -> i8;
+
+#[cfg(feature = "exec-fallback")]
+const CHILD_SCRIPT_ENTRY_FUNC_NAME: &str = "ckb_child_script_entry";
+
+// The cycles limit is an after-the-fact rejection, not a limit at spawn time:
+// neither spawn nor dynamic linking can stop a child script in the middle, so
+// the cycles it consumed are compared with the limit after it returns, and the
+// combine lock fails with CyclesLimitExceeded if they are over. A child script
+// can still run up to the cycles left in the transaction before that, but it
+// can't get a transaction accepted by consuming more than its limit.
+fn check_cycles_limit(limit: &ResourceLimit, start_cycles: u64) -> Result<(), Error> {
+    if let Some(cycles_limit) = limit.cycles_limit {
+        let cycles = current_cycles() - start_cycles;
+        if cycles > cycles_limit {
+            warn!(
+                "child script consumed {} cycles, limit is {}",
+                cycles, cycles_limit
+            );
+            return Err(Error::CyclesLimitExceeded);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "exec-fallback"))]
+pub fn run_child_script(
+    code_hash: &[u8; 32],
+    hash_type: ScriptHashType,
+    argv: &[&CStr],
+    limit: &ResourceLimit,
+    _is_last: bool,
+) -> Result<i8, Error> {
+    let start_cycles = current_cycles();
+    let spawn_ret = spawn_cell(
+        code_hash,
+        hash_type,
+        argv,
+        limit.memory_limit,
+        &mut Vec::new(),
+    )?;
+    check_cycles_limit(limit, start_cycles)?;
+    if spawn_ret != 0 {
+        warn!("spawn exited with code: {}", spawn_ret);
+    }
+    Ok(spawn_ret)
+}
+
+// For networks where spawn is not active yet: child scripts before the last
+// one are run by dynamic linking, and the last one is run by exec. On success,
+// exec never returns and the exit code of the last child script becomes the
+// one of the combine lock, without packing into ExitCode::ChildScriptFailed.
+// The memory limit is not used. The cycles limit of the last child script
+// can't be checked after exec, so a cycles limit on it is rejected with
+// InvalidResourceLimit instead of being ignored.
+#[cfg(feature = "exec-fallback")]
+pub fn run_child_script(
+    code_hash: &[u8; 32],
+    hash_type: ScriptHashType,
+    argv: &[&CStr],
+    limit: &ResourceLimit,
+    is_last: bool,
+) -> Result<i8, Error> {
+    if is_last {
+        if limit.cycles_limit.is_some() {
+            warn!("cycles limit can't be checked on the child script run by exec");
+            return Err(Error::InvalidResourceLimit);
+        }
+        return match exec_cell(code_hash, hash_type, argv) {
+            Ok(_) => unreachable!(),
+            Err(err) => Err(err.into()),
+        };
+    }
+    let func: Symbol<ChildScriptEntry> =
+        CKBDLLoader::get().get_validate_func(code_hash, hash_type, CHILD_SCRIPT_ENTRY_FUNC_NAME)?;
+    let argv: Vec<*const c_char> = argv.iter().map(|arg| arg.as_ptr()).collect();
+    let start_cycles = current_cycles();
+    let ret = unsafe { func(argv.len() as i32, argv.as_ptr()) };
+    check_cycles_limit(limit, start_cycles)?;
+    if ret != 0 {
+        warn!("dynamic linking child script exited with code: {}", ret);
+    }
+    Ok(ret)
+}
@@ -0,0 +1,80 @@
+use super::error::Error;
+use ckb_std::{
+    ckb_constants::{InputField, Source},
+    syscalls::load_input_by_field,
+};
+
+const SINCE_FLAGS_MASK: u64 = 0xff00_0000_0000_0000;
+const SINCE_VALUE_MASK: u64 = 0x00ff_ffff_ffff_ffff;
+const SINCE_RESERVED_MASK: u64 = 0x1f00_0000_0000_0000;
+const SINCE_METRIC_MASK: u64 = 0x6000_0000_0000_0000;
+const SINCE_METRIC_BLOCK_NUMBER: u64 = 0x0000_0000_0000_0000;
+const SINCE_METRIC_EPOCH: u64 = 0x2000_0000_0000_0000;
+const SINCE_METRIC_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
+
+// epoch with fraction: number(24 bits), index(16 bits), length(16 bits)
+fn epoch_parts(value: u64) -> (u64, u64, u64) {
+    (
+        value & 0xff_ffff,
+        (value >> 24) & 0xffff,
+        (value >> 40) & 0xffff,
+    )
+}
+
+fn is_valid(since: u64) -> bool {
+    if since & SINCE_RESERVED_MASK != 0 {
+        return false;
+    }
+    match since & SINCE_METRIC_MASK {
+        SINCE_METRIC_BLOCK_NUMBER | SINCE_METRIC_TIMESTAMP => true,
+        SINCE_METRIC_EPOCH => {
+            let (_, index, length) = epoch_parts(since & SINCE_VALUE_MASK);
+            (length == 0 && index == 0) || index < length
+        }
+        _ => false,
+    }
+}
+
+// Returns true if `since` is not earlier than `required`. Both must have the
+// same flags (relative or absolute, metric).
+fn is_satisfied(since: u64, required: u64) -> bool {
+    if since & SINCE_FLAGS_MASK != required & SINCE_FLAGS_MASK || !is_valid(since) {
+        return false;
+    }
+    let value = since & SINCE_VALUE_MASK;
+    let required_value = required & SINCE_VALUE_MASK;
+    if required & SINCE_METRIC_MASK == SINCE_METRIC_EPOCH {
+        let (number, index, length) = epoch_parts(value);
+        let (required_number, required_index, required_length) = epoch_parts(required_value);
+        // length 0 with index 0 is the start of the epoch
+        let length = length.max(1);
+        let required_length = required_length.max(1);
+        number > required_number
+            || (number == required_number && index * required_length >= required_index * length)
+    } else {
+        value >= required_value
+    }
+}
+
+// Checks `since` of the inputs at `input_indexes` in Source::Input against
+// `required`. The `since` itself is verified by CKB, so it's enough to compare
+// them here. Without any input, e.g. a combine type minting, only 0 is met.
+pub fn check_since(required: u64, input_indexes: &[usize]) -> Result<(), Error> {
+    if required == 0 {
+        return Ok(());
+    }
+    if !is_valid(required) {
+        return Err(Error::InvalidSince);
+    }
+    if input_indexes.is_empty() {
+        return Err(Error::SinceNotSatisfied);
+    }
+    let mut buf = [0u8; 8];
+    for &index in input_indexes {
+        load_input_by_field(&mut buf, 0, index, Source::Input, InputField::Since)?;
+        if !is_satisfied(u64::from_le_bytes(buf), required) {
+            return Err(Error::SinceNotSatisfied);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use super::error::Error;
+use alloc::vec::Vec;
+use ckb_combine_lock_types::combine_lock::{ChildScriptConfig, ChildScriptConfigV2};
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use log::warn;
+
+// Returns `args` of every child script in `array`, with the slice of user args
+// in `args_slice` of ChildScriptConfigV2 appended. An empty `args_slice` means
+// the static `args` are used for all child scripts, otherwise its length must
+// be the same as `array`, and every slice must be within user args.
+pub fn resolve_child_script_args(
+    config: &ChildScriptConfig,
+    config_v2: &Option<ChildScriptConfigV2>,
+    user_args: &[u8],
+) -> Result<Vec<Bytes>, Error> {
+    let array = config.array();
+    let args_slice = match config_v2 {
+        Some(config_v2) if !config_v2.args_slice().is_empty() => config_v2.args_slice(),
+        _ => {
+            return Ok(array
+                .into_iter()
+                .map(|child_script| child_script.args().unpack())
+                .collect())
+        }
+    };
+    if args_slice.len() != array.len() {
+        return Err(Error::InvalidArgsSlice);
+    }
+    let mut result = Vec::with_capacity(array.len());
+    for (child_script, slice) in array.into_iter().zip(args_slice.into_iter()) {
+        let offset = u16::from_le_bytes(slice.offset().as_slice().try_into().unwrap()) as usize;
+        let length = u16::from_le_bytes(slice.length().as_slice().try_into().unwrap()) as usize;
+        if offset + length > user_args.len() {
+            warn!(
+                "args slice [{}, {}) is out of user args, length = {}",
+                offset,
+                offset + length,
+                user_args.len()
+            );
+            return Err(Error::InvalidArgsSlice);
+        }
+        let mut args: Vec<u8> = child_script.args().raw_data().to_vec();
+        args.extend_from_slice(&user_args[offset..offset + length]);
+        result.push(args.into());
+    }
+    Ok(result)
+}
@@ -0,0 +1,124 @@
+// don't add extra code in the file.
+// it will be included in test cases in native code.
+//
+// The cursor passed to a child script in argv, pointing to the bytes in a
+// witness it should verify, e.g. an `inner_witness` of CombineLockWitness.
+//
+// Legacy text, the witness is decided by the child script:
+// `offset:size`, both are u32 in hex
+// ckb-combine-lock with legacy args still passes it when the cursor points
+// into the first witness in Source::GroupInput.
+//
+// Version 1, passed as `cursor:` followed by hex of the binary format below,
+// see simple_cursor.rs. All integers are little endian:
+// version: u8, it's 1 now
+// source: u8, 0 if the witness is in Source::GroupInput, 1 if it's in
+//     Source::GroupOutput
+// witness_index: u32, index of the witness in `source`
+// offset: u32
+// size: u32
+// field: u8, 0 if not specified, otherwise the field of WitnessArgs the range
+//     must be inside: 1 lock, 2 input_type, 3 output_type
+//
+// Unknown versions, sources, fields and lengths are rejected, so are the
+// ranges whose end overflows u32.
+
+use super::error::Error;
+
+pub const CURSOR_ARG_VERSION: u8 = 1;
+pub const CURSOR_ARG_LEN: usize = 1 + 1 + 4 + 4 + 4 + 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CursorSource {
+    GroupInput,
+    GroupOutput,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WitnessField {
+    Lock,
+    InputType,
+    OutputType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CursorArg {
+    pub source: CursorSource,
+    pub witness_index: u32,
+    pub offset: u32,
+    pub size: u32,
+    pub field: Option<WitnessField>,
+}
+
+impl CursorArg {
+    pub fn to_bytes(&self) -> [u8; CURSOR_ARG_LEN] {
+        let mut result = [0u8; CURSOR_ARG_LEN];
+        result[0] = CURSOR_ARG_VERSION;
+        result[1] = match self.source {
+            CursorSource::GroupInput => 0,
+            CursorSource::GroupOutput => 1,
+        };
+        result[2..6].copy_from_slice(&self.witness_index.to_le_bytes());
+        result[6..10].copy_from_slice(&self.offset.to_le_bytes());
+        result[10..14].copy_from_slice(&self.size.to_le_bytes());
+        result[14] = match self.field {
+            None => 0,
+            Some(WitnessField::Lock) => 1,
+            Some(WitnessField::InputType) => 2,
+            Some(WitnessField::OutputType) => 3,
+        };
+        result
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        if data.len() != CURSOR_ARG_LEN || data[0] != CURSOR_ARG_VERSION {
+            return Err(Error::InvalidCursorArg);
+        }
+        let source = match data[1] {
+            0 => CursorSource::GroupInput,
+            1 => CursorSource::GroupOutput,
+            _ => return Err(Error::InvalidCursorArg),
+        };
+        let n = |i: usize| u32::from_le_bytes(data[i..i + 4].try_into().unwrap());
+        let (offset, size) = (n(6), n(10));
+        if offset.checked_add(size).is_none() {
+            return Err(Error::InvalidCursorArg);
+        }
+        let field = match data[14] {
+            0 => None,
+            1 => Some(WitnessField::Lock),
+            2 => Some(WitnessField::InputType),
+            3 => Some(WitnessField::OutputType),
+            _ => return Err(Error::InvalidCursorArg),
+        };
+        Ok(Self {
+            source,
+            witness_index: n(2),
+            offset,
+            size,
+            field,
+        })
+    }
+}
+
+// Parses the legacy `offset:size` text into (offset, size).
+pub fn parse_legacy_cursor(text: &[u8]) -> Result<(u32, u32), Error> {
+    let mut parts = text.split(|&c| c == b':');
+    let (offset, size) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(offset), Some(size), None) => (parse_hex_u32(offset)?, parse_hex_u32(size)?),
+        _ => return Err(Error::WrongHex),
+    };
+    if offset.checked_add(size).is_none() {
+        return Err(Error::WrongHex);
+    }
+    Ok((offset, size))
+}
+
+fn parse_hex_u32(text: &[u8]) -> Result<u32, Error> {
+    // `from_str_radix` accepts a leading sign, which is not hex.
+    if text.is_empty() || !text.iter().all(u8::is_ascii_hexdigit) {
+        return Err(Error::WrongHex);
+    }
+    let text = core::str::from_utf8(text).map_err(|_| Error::WrongHex)?;
+    u32::from_str_radix(text, 16).map_err(|_| Error::WrongHex)
+}
@@ -0,0 +1,43 @@
+// Verification of a building packet in a SighashAll witness. See envelope.rs in
+// ckb-combine-lock-types for the signing message.
+
+use crate::blake2b::Blake2bBuilder;
+use crate::error::Error;
+use alloc::vec::Vec;
+use ckb_combine_lock_types::cobuild::MessageReader;
+pub use ckb_combine_lock_types::envelope::parse_envelope;
+use ckb_combine_lock_types::envelope::{
+    find_unknown_action, update_signing_message, COBUILD_PERSONALIZATION,
+};
+use ckb_std::ckb_constants::Source;
+use ckb_std::high_level::{load_cell_lock_hash, load_cell_type_hash, QueryIter};
+use log::warn;
+
+// `sighash` is the message of generate_sighash_all, with the whole SighashAll
+// witness zeroed.
+pub fn generate_signing_message(message: &MessageReader, sighash: &[u8; 32]) -> [u8; 32] {
+    let mut ctx = Blake2bBuilder::new(32)
+        .personal(COBUILD_PERSONALIZATION)
+        .build();
+    update_signing_message(message, sighash, |data| ctx.update(data));
+    let mut msg = [0u8; 32];
+    ctx.finalize(&mut msg);
+    msg
+}
+
+// The scripts an action can be for: lock and type scripts of inputs, and type
+// scripts of outputs.
+pub fn tx_script_hashes() -> Vec<[u8; 32]> {
+    let mut hashes: Vec<[u8; 32]> = QueryIter::new(load_cell_lock_hash, Source::Input).collect();
+    hashes.extend(QueryIter::new(load_cell_type_hash, Source::Input).flatten());
+    hashes.extend(QueryIter::new(load_cell_type_hash, Source::Output).flatten());
+    hashes
+}
+
+pub fn check_actions(message: &MessageReader) -> Result<(), Error> {
+    if let Some(index) = find_unknown_action(message, &tx_script_hashes()) {
+        warn!("action {} is not for a script in the transaction", index);
+        return Err(Error::UnknownAction);
+    }
+    Ok(())
+}
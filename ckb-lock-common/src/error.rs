@@ -26,6 +26,10 @@ pub enum Error {
     DanglingPair,
 
     Unknown,
+    InvalidParentContext,
+    InvalidSighashMode,
+    UnknownAction,
+    InvalidCursorArg,
 }
 
 impl From<SysError> for Error {
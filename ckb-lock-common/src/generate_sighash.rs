@@ -0,0 +1,276 @@
+// Sighash modes, for signatures committing to part of the transaction, so that
+// inputs or outputs can be added after signing.
+//
+// Encoding, e.g. at the start of the witness of a child script:
+// | 1 byte mode | 8 bytes output range, only with SIGHASH_OUTPUT_RANGE |
+// mode bit 0-5, the signed outputs:
+//     1 SIGHASH_ALL: all outputs
+//     2 SIGHASH_NONE: no outputs
+//     3 SIGHASH_SINGLE: outputs at the same indexes as the inputs in the
+//         script group
+//     4 SIGHASH_OUTPUT_RANGE: outputs in [start, end), both u32 little endian
+// mode bit 6, SIGHASH_WITNESS_DIGEST: witnesses are signed by their digests,
+//     see sighash_cache.rs, so child scripts of ckb-combine-lock can reuse the
+//     digests computed by it
+// mode bit 7, SIGHASH_ANYONECANPAY: only the inputs in the script group are
+//     signed, instead of all inputs
+//
+// SIGHASH_ALL without other bits is the message of generate_sighash_all.
+// SIGHASH_ALL | SIGHASH_WITNESS_DIGEST is blake2b of:
+// | mode encoding | tx hash | group digests | uncovered digests |
+// Otherwise, the message is blake2b of:
+// | mode encoding | version | cell deps | header deps | signed inputs |
+// | signed outputs | witnesses |
+// version: u32
+// cell deps, header deps: the CellDepVec and Byte32Vec in the transaction, as
+//     they are serialized by molecule. They are always signed: a cell dep or
+//     header dep added after signing could change what a script in the
+//     transaction runs or reads.
+// signed inputs: u64 count, then u64 index and CellInput of each
+// signed outputs: u64 count, then u64 index, CellOutput and data hash of each
+// witnesses: the witnesses in the script group, same as generate_sighash_all,
+//     or group digests with SIGHASH_WITNESS_DIGEST
+// group digests: u64 count, then the digest of each witness in the script group
+// uncovered digests: u64 count, then the digest of each witness not covered by
+//     inputs
+// All integers are little endian. The witnesses not in the script group are
+// not signed, unless it's SIGHASH_ALL.
+
+use crate::blake2b::new_blake2b;
+use crate::error::Error;
+use crate::generate_sighash_all::{
+    check_masks, generate_sighash_all_with_masks, hash_witness_with_masks,
+};
+use crate::generated::blockchain::Transaction;
+use crate::sighash_cache::{
+    digest_group_witnesses, digest_uncovered_witnesses, update_witness_digests, SighashCache,
+};
+use crate::simple_cursor::{get_witness_len, SimpleCursor, TransactionDataSource};
+use alloc::vec::Vec;
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::prelude::*;
+use ckb_std::error::SysError;
+use ckb_std::high_level::{
+    load_cell, load_cell_capacity, load_cell_data_hash, load_cell_lock_hash, load_cell_type_hash,
+    load_input, load_script_hash, load_tx_hash, QueryIter,
+};
+
+pub const SIGHASH_ALL: u8 = 1;
+pub const SIGHASH_NONE: u8 = 2;
+pub const SIGHASH_SINGLE: u8 = 3;
+pub const SIGHASH_OUTPUT_RANGE: u8 = 4;
+pub const SIGHASH_WITNESS_DIGEST: u8 = 0x40;
+pub const SIGHASH_ANYONECANPAY: u8 = 0x80;
+
+pub enum SighashOutputs {
+    All,
+    None,
+    Single,
+    Range(u32, u32),
+}
+
+pub struct SighashMode {
+    pub outputs: SighashOutputs,
+    pub anyone_can_pay: bool,
+    pub witness_digest: bool,
+}
+
+impl SighashMode {
+    // Parses the mode at the start of `data`. Returns it with the length of
+    // its encoding.
+    pub fn parse(data: &[u8]) -> Result<(Self, usize), Error> {
+        let mode = *data.first().ok_or(Error::InvalidSighashMode)?;
+        let (outputs, len) = match mode & !(SIGHASH_ANYONECANPAY | SIGHASH_WITNESS_DIGEST) {
+            SIGHASH_ALL => (SighashOutputs::All, 1),
+            SIGHASH_NONE => (SighashOutputs::None, 1),
+            SIGHASH_SINGLE => (SighashOutputs::Single, 1),
+            SIGHASH_OUTPUT_RANGE => {
+                let range = data.get(1..9).ok_or(Error::InvalidSighashMode)?;
+                let start = u32::from_le_bytes(range[..4].try_into().unwrap());
+                let end = u32::from_le_bytes(range[4..].try_into().unwrap());
+                if start > end {
+                    return Err(Error::InvalidSighashMode);
+                }
+                (SighashOutputs::Range(start, end), 9)
+            }
+            _ => return Err(Error::InvalidSighashMode),
+        };
+        let anyone_can_pay = mode & SIGHASH_ANYONECANPAY != 0;
+        let witness_digest = mode & SIGHASH_WITNESS_DIGEST != 0;
+        Ok((
+            Self {
+                outputs,
+                anyone_can_pay,
+                witness_digest,
+            },
+            len,
+        ))
+    }
+
+    // SIGHASH_ALL without other bits, signing the message of
+    // generate_sighash_all.
+    pub fn is_sighash_all(&self) -> bool {
+        matches!(self.outputs, SighashOutputs::All) && !self.anyone_can_pay && !self.witness_digest
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(9);
+        let mut mode = match self.outputs {
+            SighashOutputs::All => SIGHASH_ALL,
+            SighashOutputs::None => SIGHASH_NONE,
+            SighashOutputs::Single => SIGHASH_SINGLE,
+            SighashOutputs::Range(_, _) => SIGHASH_OUTPUT_RANGE,
+        };
+        if self.anyone_can_pay {
+            mode |= SIGHASH_ANYONECANPAY;
+        }
+        if self.witness_digest {
+            mode |= SIGHASH_WITNESS_DIGEST;
+        }
+        result.push(mode);
+        if let SighashOutputs::Range(start, end) = self.outputs {
+            result.extend_from_slice(&start.to_le_bytes());
+            result.extend_from_slice(&end.to_le_bytes());
+        }
+        result
+    }
+}
+
+// `group_input_indexes` are indexes in Source::Input of the inputs in the
+// script group. `source` and `targets` are the same as the ones of
+// `generate_sighash_all_with_masks`.
+pub fn generate_sighash(
+    mode: &SighashMode,
+    group_input_indexes: &[usize],
+    source: Source,
+    targets: &[(usize, SimpleCursor)],
+) -> Result<[u8; 32], Error> {
+    generate_sighash_with_cache(mode, group_input_indexes, source, targets, None)
+}
+
+// Same as `generate_sighash`, but the witness digests are taken from `cache`
+// when it's provided. The cache must be computed with the same `source` and
+// `targets`, e.g. the one in ParentContext with its masks.
+pub fn generate_sighash_with_cache(
+    mode: &SighashMode,
+    group_input_indexes: &[usize],
+    source: Source,
+    targets: &[(usize, SimpleCursor)],
+    cache: Option<&SighashCache>,
+) -> Result<[u8; 32], Error> {
+    if mode.is_sighash_all() {
+        return generate_sighash_all_with_masks(source, targets);
+    }
+    // only with SIGHASH_WITNESS_DIGEST
+    let sign_all = matches!(
+        (&mode.outputs, mode.anyone_can_pay),
+        (SighashOutputs::All, false)
+    );
+    let mut ctx = new_blake2b();
+    ctx.update(&mode.to_bytes());
+
+    if sign_all {
+        let tx_hash = match cache {
+            Some(cache) => cache.tx_hash,
+            None => load_tx_hash()?,
+        };
+        ctx.update(&tx_hash);
+    } else {
+        let raw_tx = Transaction::from(TransactionDataSource.as_cursor()?).raw();
+        ctx.update(&raw_tx.version().to_le_bytes());
+        for deps in [raw_tx.cell_deps().cursor, raw_tx.header_deps().cursor] {
+            let deps: Vec<u8> = deps.try_into().map_err(|_| Error::Encoding)?;
+            ctx.update(&deps);
+        }
+
+        let input_indexes: Vec<usize> = if mode.anyone_can_pay {
+            group_input_indexes.to_vec()
+        } else {
+            (0..QueryIter::new(load_input, Source::Input).count()).collect()
+        };
+        ctx.update(&(input_indexes.len() as u64).to_le_bytes());
+        for &i in &input_indexes {
+            ctx.update(&(i as u64).to_le_bytes());
+            ctx.update(load_input(i, Source::Input)?.as_slice());
+        }
+
+        // Either a range of outputs or the ones at `group_input_indexes`. The
+        // range is checked against the outputs before it's iterated, it can be
+        // up to 2^32 long.
+        let output_count = || QueryIter::new(load_cell_capacity, Source::Output).count();
+        let (range, single) = match mode.outputs {
+            SighashOutputs::All => (0..output_count(), &[][..]),
+            SighashOutputs::None => (0..0, &[][..]),
+            SighashOutputs::Single => (0..0, group_input_indexes),
+            SighashOutputs::Range(start, end) => {
+                if end as usize > output_count() {
+                    return Err(Error::InvalidSighashMode);
+                }
+                (start as usize..end as usize, &[][..])
+            }
+        };
+        ctx.update(&((range.len() + single.len()) as u64).to_le_bytes());
+        for i in range.chain(single.iter().copied()) {
+            ctx.update(&(i as u64).to_le_bytes());
+            ctx.update(load_cell(i, Source::Output)?.as_slice());
+            ctx.update(&load_cell_data_hash(i, Source::Output)?);
+        }
+    }
+
+    if mode.witness_digest {
+        match cache {
+            Some(cache) => {
+                update_witness_digests(&mut ctx, &cache.group);
+                if sign_all {
+                    update_witness_digests(&mut ctx, &cache.uncovered);
+                }
+            }
+            None => {
+                update_witness_digests(&mut ctx, &digest_group_witnesses(source, targets)?);
+                if sign_all {
+                    update_witness_digests(&mut ctx, &digest_uncovered_witnesses()?);
+                }
+            }
+        }
+    } else {
+        check_masks(source, targets)?;
+        // The first witness in the script group must exist.
+        get_witness_len(0, source)?;
+        let mut index = 0;
+        while hash_witness_with_masks(&mut ctx, index, source, targets) {
+            index += 1;
+        }
+    }
+    let mut msg = [0u8; 32];
+    ctx.finalize(&mut msg);
+    Ok(msg)
+}
+
+// Indexes in Source::Input of the inputs in the script group of the current
+// script, either a lock script or a type script. A child script of
+// ckb-combine-lock or ckb-combine-type runs in the script group of its parent,
+// and it signs the whole group, not only the inputs unlocked by its witness,
+// so the message is the same with or without ParentContext.
+pub fn group_input_indexes() -> Result<Vec<usize>, Error> {
+    let script_hash = load_script_hash()?;
+    let is_lock = match load_cell_lock_hash(0, Source::GroupInput) {
+        Ok(lock_hash) => lock_hash == script_hash,
+        // a type script group with outputs only
+        Err(SysError::IndexOutOfBound) => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let indexes = if is_lock {
+        QueryIter::new(load_cell_lock_hash, Source::Input)
+            .enumerate()
+            .filter(|(_, lock_hash)| lock_hash == &script_hash)
+            .map(|(i, _)| i)
+            .collect()
+    } else {
+        QueryIter::new(load_cell_type_hash, Source::Input)
+            .enumerate()
+            .filter(|(_, type_hash)| type_hash == &Some(script_hash))
+            .map(|(i, _)| i)
+            .collect()
+    };
+    Ok(indexes)
+}
@@ -11,26 +11,33 @@ use ckb_std::syscalls::{load_input_by_field, load_witness, SysError};
 const CHUNK_SIZE: usize = 32768;
 
 pub fn generate_sighash_all(target: &SimpleCursor) -> Result<[u8; 32], Error> {
-    // Digest first witness in the script group.
-    let chunks = ChunksLoader::new(load_witness, CHUNK_SIZE, 0, Source::GroupInput).into_iter();
+    generate_sighash_all_with_masks(
+        Source::GroupInput,
+        &[(0, SimpleCursor::new(target.offset, target.size))],
+    )
+}
+
+// Same as `generate_sighash_all`, but all `targets` are zeroed. Each target is
+// a range in the witness at the given index of `source`. It's used when the
+// message is shared by several child scripts, or by several witnesses in the
+// script group. The `source` is Source::GroupInput, or Source::GroupOutput for
+// a type script without group input. Targets may overlap, and they're checked
+// by `check_masks`.
+pub fn generate_sighash_all_with_masks(
+    source: Source,
+    targets: &[(usize, SimpleCursor)],
+) -> Result<[u8; 32], Error> {
+    check_masks(source, targets)?;
     let mut ctx = new_blake2b();
     let tx_hash = load_tx_hash()?;
     ctx.update(&tx_hash);
-    let total_len = get_witness_len(0, Source::GroupInput)?;
-    ctx.update(&(total_len as u64).to_le_bytes());
-    let mut chunk_offset = 0;
-    let target = target.offset as usize..target.offset as usize + target.size as usize;
-    for (_, mut chunk) in chunks {
-        if let Some(slice) =
-            get_intersection(chunk_offset..chunk_offset + chunk.len(), target.clone())
-        {
-            chunk[slice.start - chunk_offset..slice.end - chunk_offset].fill(0);
-        }
-        ctx.update(&chunk);
-        chunk_offset += chunk.len();
+    // The first witness in the script group must exist.
+    get_witness_len(0, source)?;
+    // Digest witnesses in the script group.
+    let mut index = 0;
+    while hash_witness_with_masks(&mut ctx, index, source, targets) {
+        index += 1;
     }
-    // Digest other witnesses in the script group.
-    load_and_hash_witness(&mut ctx, 1, Source::GroupInput);
     // Digest witnesses that not covered by inputs.
     let i = calculate_inputs_len()?;
     load_and_hash_witness(&mut ctx, i, Source::Input);
@@ -39,6 +46,67 @@ pub fn generate_sighash_all(target: &SimpleCursor) -> Result<[u8; 32], Error> {
     Ok(msg)
 }
 
+// Every target must be inside its witness. Otherwise the bytes it's meant to
+// zero would be signed silently.
+pub fn check_masks(source: Source, targets: &[(usize, SimpleCursor)]) -> Result<(), Error> {
+    for (index, target) in targets {
+        let len = get_witness_len(*index, source)?;
+        if target.offset as usize + target.size as usize > len {
+            return Err(Error::LengthNotEnough);
+        }
+    }
+    Ok(())
+}
+
+// Returns false if there is no witness at `index`.
+pub fn hash_witness_with_masks(
+    ctx: &mut Blake2b,
+    index: usize,
+    source: Source,
+    targets: &[(usize, SimpleCursor)],
+) -> bool {
+    let mut first = true;
+    stream_witness_with_masks(index, source, targets, |total_len, chunk| {
+        if first {
+            ctx.update(&(total_len as u64).to_le_bytes());
+            first = false;
+        }
+        ctx.update(chunk);
+    })
+}
+
+// Loads the witness at `index` chunk by chunk, with the `targets` at `index`
+// zeroed. `f` is called with the length of the witness and each chunk. Returns
+// false if there is no witness at `index`.
+pub(crate) fn stream_witness_with_masks<F: FnMut(usize, &[u8])>(
+    index: usize,
+    source: Source,
+    targets: &[(usize, SimpleCursor)],
+    mut f: F,
+) -> bool {
+    let targets: Vec<_> = targets
+        .iter()
+        .filter(|(i, _)| *i == index)
+        .map(|(_, t)| t.offset as usize..t.offset as usize + t.size as usize)
+        .collect();
+    let mut chunks = ChunksLoader::new(load_witness, CHUNK_SIZE, index, source).into_iter();
+    let mut chunk_offset = 0;
+    let mut found = false;
+    while let Some((total_len, mut chunk)) = chunks.next() {
+        found = true;
+        for target in &targets {
+            if let Some(slice) =
+                get_intersection(chunk_offset..chunk_offset + chunk.len(), target.clone())
+            {
+                chunk[slice.start - chunk_offset..slice.end - chunk_offset].fill(0);
+            }
+        }
+        f(total_len, &chunk);
+        chunk_offset += chunk.len();
+    }
+    found
+}
+
 fn load_and_hash_witness(ctx: &mut Blake2b, mut start_index: usize, source: Source) {
     loop {
         let mut chunks =
@@ -56,7 +124,7 @@ fn load_and_hash_witness(ctx: &mut Blake2b, mut start_index: usize, source: Sour
     }
 }
 
-fn calculate_inputs_len() -> Result<usize, Error> {
+pub fn calculate_inputs_len() -> Result<usize, Error> {
     let mut temp = [0u8; 8];
     let mut i = 0;
     loop {
@@ -198,3 +198,353 @@ impl CombineLockWitness {
         }
     }
 }
+
+pub struct ThresholdVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for ThresholdVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl ThresholdVec {
+    pub fn len(&self) -> usize {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl ThresholdVec {
+    pub fn get(&self, index: usize) -> u8 {
+        let cur = self.cursor.fixvec_slice_by_index(1, index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct SinceVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for SinceVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl SinceVec {
+    pub fn len(&self) -> usize {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl SinceVec {
+    pub fn get(&self, index: usize) -> Cursor {
+        let cur = self.cursor.fixvec_slice_by_index(8, index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct CapacityLimitVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for CapacityLimitVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl CapacityLimitVec {
+    pub fn len(&self) -> usize {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl CapacityLimitVec {
+    pub fn get(&self, index: usize) -> Cursor {
+        let cur = self.cursor.fixvec_slice_by_index(8, index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct MemoryLimitVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for MemoryLimitVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl MemoryLimitVec {
+    pub fn len(&self) -> usize {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl MemoryLimitVec {
+    pub fn get(&self, index: usize) -> u8 {
+        let cur = self.cursor.fixvec_slice_by_index(1, index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct CyclesLimitVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for CyclesLimitVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl CyclesLimitVec {
+    pub fn len(&self) -> usize {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl CyclesLimitVec {
+    pub fn get(&self, index: usize) -> Cursor {
+        let cur = self.cursor.fixvec_slice_by_index(8, index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct ArgsSlice {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for ArgsSlice {
+    fn from(cursor: Cursor) -> Self {
+        ArgsSlice { cursor }
+    }
+}
+
+impl ArgsSlice {
+    pub fn offset(&self) -> u16 {
+        let cur = self.cursor.slice_by_offset(0, 2).unwrap();
+        cur.into()
+    }
+}
+
+impl ArgsSlice {
+    pub fn length(&self) -> u16 {
+        let cur = self.cursor.slice_by_offset(2, 2).unwrap();
+        cur.into()
+    }
+}
+
+pub struct ArgsSliceVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for ArgsSliceVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl ArgsSliceVec {
+    pub fn len(&self) -> usize {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl ArgsSliceVec {
+    pub fn get(&self, index: usize) -> ArgsSlice {
+        let cur = self.cursor.fixvec_slice_by_index(4, index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct PolicyNode {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for PolicyNode {
+    fn from(cursor: Cursor) -> Self {
+        PolicyNode { cursor }
+    }
+}
+
+impl PolicyNode {
+    pub fn kind(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl PolicyNode {
+    pub fn threshold(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+impl PolicyNode {
+    pub fn children(&self) -> Cursor {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2
+    }
+}
+
+pub struct PolicyNodeVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for PolicyNodeVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl PolicyNodeVec {
+    pub fn len(&self) -> usize {
+        self.cursor.dynvec_length()
+    }
+}
+
+impl PolicyNodeVec {
+    pub fn get(&self, index: usize) -> PolicyNode {
+        let cur = self.cursor.dynvec_slice_by_index(index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct ChildScriptConfigV2 {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for ChildScriptConfigV2 {
+    fn from(cursor: Cursor) -> Self {
+        ChildScriptConfigV2 { cursor }
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn array(&self) -> ChildScriptArray {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn index(&self) -> ChildScriptVecVec {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn threshold(&self) -> Cursor {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn policy(&self) -> PolicyNodeVec {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        cur.into()
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn since(&self) -> SinceVec {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        cur.into()
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn output_capacity_limit(&self) -> CapacityLimitVec {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        cur.into()
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn memory_limit(&self) -> Cursor {
+        let cur = self.cursor.table_slice_by_index(6).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn cycles_limit(&self) -> CyclesLimitVec {
+        let cur = self.cursor.table_slice_by_index(7).unwrap();
+        cur.into()
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn args_slice(&self) -> ArgsSliceVec {
+        let cur = self.cursor.table_slice_by_index(8).unwrap();
+        cur.into()
+    }
+}
+
+impl ChildScriptConfigV2 {
+    pub fn fee_limit(&self) -> CapacityLimitVec {
+        let cur = self.cursor.table_slice_by_index(9).unwrap();
+        cur.into()
+    }
+}
+
+pub struct CombineLockWitnessV2 {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for CombineLockWitnessV2 {
+    fn from(cursor: Cursor) -> Self {
+        CombineLockWitnessV2 { cursor }
+    }
+}
+
+impl CombineLockWitnessV2 {
+    pub fn index(&self) -> u16 {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl CombineLockWitnessV2 {
+    pub fn inner_witness(&self) -> BytesVec {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+impl CombineLockWitnessV2 {
+    pub fn script_config(&self) -> Option<ChildScriptConfig> {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        if cur.option_is_none() {
+            None
+        } else {
+            Some(cur.into())
+        }
+    }
+}
+
+impl CombineLockWitnessV2 {
+    pub fn selection(&self) -> Cursor {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2
+    }
+}
+
+impl CombineLockWitnessV2 {
+    pub fn inner_witness_index(&self) -> Cursor {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2
+    }
+}
@@ -1,14 +1,21 @@
 #![no_std]
 extern crate alloc;
 
+pub mod auth_args;
 pub mod blake2b;
 pub mod ckb_auth;
+pub mod combine_lock;
+pub mod cursor_arg;
+pub mod envelope;
 pub mod error;
+pub mod generate_sighash;
 pub mod generate_sighash_all;
 pub mod generated;
 pub mod intersection;
 pub mod lock_wrapper;
 pub mod logger;
+pub mod parent_context;
+pub mod sighash_cache;
 pub mod simple_cursor;
 pub mod transforming;
 pub mod utils;
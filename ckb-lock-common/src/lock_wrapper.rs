@@ -1,12 +1,10 @@
 extern crate alloc;
 
 use crate::{
+    combine_lock::args::{CombineLockArgs, ConfigSource},
     error::Error,
     transforming::{self, BatchTransformingStatus},
-    utils::{
-        config_cell_unchanged, get_current_hash, get_global_registry_id, get_next_hash,
-        get_wrapped_script_hash, NEXT_HASH_LEN,
-    },
+    utils::{config_cell_unchanged, get_current_hash, get_next_hash, NEXT_HASH_LEN},
 };
 use alloc::vec::Vec;
 use ckb_std::{
@@ -29,15 +27,13 @@ pub enum LockWrapperResult {
 }
 
 /// An entry to handle global registry processing. Make it easy for lock scripts
-/// to adopt global registry.
+/// to adopt global registry. Combine lock calls it when the config source in
+/// its args is global registry, see combine_lock/args.rs for the layout.
 ///
 /// * `global_registry_id` - type script hash of a config cell
 /// * `child_script_config_hash` - Hash of child script config. A 2-D
 /// dimensioned array of child scripts. It is usually stored in config cell or
 /// provided in witness. It is ChildScriptConfig type in molecule format.
-/// * `prefix_flag_len` - Scripts which adopt global registry have different
-/// `args` layout. This variable indicates the length of leading bytes defined
-/// by lock scripts. In combine lock, there is an extra leading 1 byte flag.
 pub fn lock_wrapper_entry(
     global_registry_id: &[u8; 32],
     child_script_config_hash: &[u8; 32],
@@ -95,13 +91,22 @@ fn fetch_child_script_config(
         {
             continue;
         }
+        // the layout of lock script args is same as combine lock, see
+        // combine_lock/args.rs. Config cells created before the versioned
+        // layout use the legacy | 1 | ID | config hash | and are parsed
+        // the same way.
         let args = &config_cell_lock_script.args().raw_data();
-        if args[0] != 1u8 {
-            continue;
-        }
-        if &get_global_registry_id(args) != global_registry_id {
-            continue;
-        }
+        let current_hash = match CombineLockArgs::parse(args) {
+            Ok(CombineLockArgs {
+                config_source:
+                    ConfigSource::GlobalRegistry {
+                        global_registry_id: id,
+                        config_hash,
+                    },
+                ..
+            }) if &id == global_registry_id => config_hash,
+            _ => continue,
+        };
 
         // the layout of config cell data:
         // | 32 bytes next hash | variable length bytes |
@@ -113,9 +118,6 @@ fn fetch_child_script_config(
             );
             return Err(Error::InvalidDataLength);
         }
-        // the layout of lock script args is same as combine lock:
-        // | 1 byte flag | 32 bytes global registry ID | 32 bytes child script config hash |
-        let current_hash: [u8; 32] = get_wrapped_script_hash(args).try_into().unwrap();
         match current_hash.cmp(child_script_config_hash) {
             Ordering::Equal => {
                 return Ok(LockWrapperResult::ChildScriptConfig(
@@ -159,7 +161,7 @@ fn validate_config_cell(global_registry_id: &[u8; 32]) -> Result<LockWrapperResu
     let iter = QueryIter::new(load_cell_type_hash, Source::Input);
     for (i, hash) in iter.enumerate() {
         if hash == Some(*global_registry_id) {
-            let current_hash = get_current_hash(i, Source::Input).unwrap();
+            let current_hash = get_current_hash(i, Source::Input)?;
             let next_hash = get_next_hash(i, Source::Input).unwrap();
             let cell = transforming::Cell::new(i, current_hash, next_hash);
             info!("set_input = {}", cell);
@@ -169,7 +171,7 @@ fn validate_config_cell(global_registry_id: &[u8; 32]) -> Result<LockWrapperResu
     let iter = QueryIter::new(load_cell_type_hash, Source::Output);
     for (i, hash) in iter.enumerate() {
         if hash == Some(*global_registry_id) {
-            let current_hash = get_current_hash(i, Source::Output).unwrap();
+            let current_hash = get_current_hash(i, Source::Output)?;
             let next_hash = get_next_hash(i, Source::Output).unwrap();
             let cell = transforming::Cell::new(i, current_hash, next_hash);
             info!("set_output = {}", cell);
@@ -215,9 +217,16 @@ fn validate_config_cell(global_registry_id: &[u8; 32]) -> Result<LockWrapperResu
                 warn!("by pass routine!");
                 exit(0);
             } else {
-                // if it's not CC(0), the current script must be an AC.
-                let hash = get_wrapped_script_hash(&current_script.args().raw_data());
-                return Ok(LockWrapperResult::ChildScriptConfigHash(hash));
+                // if it's not CC(0), the current script must be an AC. Its
+                // args can have user args after the config hash.
+                let args = &current_script.args().raw_data();
+                return match CombineLockArgs::parse(args) {
+                    Ok(CombineLockArgs {
+                        config_source: ConfigSource::GlobalRegistry { config_hash, .. },
+                        ..
+                    }) => Ok(LockWrapperResult::ChildScriptConfigHash(config_hash)),
+                    _ => Err(Error::WrongGlobalRegistry),
+                };
             }
         } else {
             // updating, the ChildScriptConfig should in data
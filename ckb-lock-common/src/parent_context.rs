@@ -0,0 +1,228 @@
+// Context computed once by ckb-combine-lock and passed to its child scripts.
+//
+// It's passed as the last argv item: `context:` followed by hex of the binary
+// format below. argv is the only input shared by all the ways a child script
+// runs: spawn in ckb-std 0.14 has no pipes or inherited file descriptors, and
+// exec and dynamic linking with "exec-fallback" have none either.
+//
+// Only the combine locks with args version 1 (see args.rs) pass it. The legacy
+// args keep the argv of child scripts written before it, which check
+// `env::argv().len() == 2`. A child script which understands it should use
+// `argv_len` instead of `env::argv().len()` to tell how it is called.
+//
+// Binary format, all integers are little endian:
+// version: u8, it's 5 now
+// message: [u8; 32], sighash-all with all `inner_witness` items zeroed
+// witness_index: u16, `index` in CombineLockWitness
+// position: u8, position of the child script in running order
+// group_input_count: u32
+// group_input_indexes: [u32; group_input_count], indexes in Source::Input of
+//     the inputs unlocked by the witness. Sighash modes sign the whole script
+//     group instead, see `group_input_indexes` in generate_sighash.rs.
+// group_witness_index: u32, index in `group_witness_source` of the witness
+//     which the cursor in argv points into, since version 2
+// group_witness_source: u8, 0 if the witness is in Source::GroupInput, 1 if
+//     it's in Source::GroupOutput, since version 3
+// mask_count: u32, since version 4
+// masks: [(u32, u32, u32); mask_count], the ranges zeroed in `message`, each
+//     is the index in `group_witness_source` of a witness, the offset and the
+//     size. A child script signing with another sighash mode uses them.
+// tx_hash: [u8; 32], since version 5
+// group_digest_count: u32, since version 5
+// group_digests: [(u64, [u8; 32]); group_digest_count], the digests of the
+//     witnesses in `group_witness_source`, with `masks` zeroed
+// uncovered_digest_count: u32, since version 5
+// uncovered_digests: [(u64, [u8; 32]); uncovered_digest_count], the digests
+//     of the witnesses not covered by inputs
+// The digests are described in sighash_cache.rs. A child script signing with
+// SIGHASH_WITNESS_DIGEST uses them instead of loading the witnesses again.
+//
+// Newer versions can only append fields, so a child script can read the
+// fields it knows from a context with larger version.
+
+use crate::error::Error;
+use crate::sighash_cache::{SighashCache, WitnessDigest, WITNESS_DIGEST_LEN};
+use crate::simple_cursor::SimpleCursor;
+use alloc::{format, string::String, vec::Vec};
+use ckb_std::{ckb_constants::Source, env};
+use hex::{decode, encode};
+
+pub const CONTEXT_ARG_PREFIX: &str = "context:";
+pub const CONTEXT_VERSION: u8 = 5;
+const CONTEXT_V1_FIXED_LEN: usize = 1 + 32 + 2 + 1 + 4;
+
+pub struct ParentContext {
+    pub version: u8,
+    pub message: [u8; 32],
+    pub witness_index: u16,
+    pub position: u8,
+    pub group_input_indexes: Vec<u32>,
+    pub group_witness_index: u32,
+    pub group_witness_source: Source,
+    pub masks: Vec<(usize, SimpleCursor)>,
+    // None before version 5
+    pub sighash_cache: Option<SighashCache>,
+}
+
+impl ParentContext {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result =
+            Vec::with_capacity(CONTEXT_V1_FIXED_LEN + self.group_input_indexes.len() * 4 + 5);
+        result.push(self.version);
+        result.extend_from_slice(&self.message);
+        result.extend_from_slice(&self.witness_index.to_le_bytes());
+        result.push(self.position);
+        result.extend_from_slice(&(self.group_input_indexes.len() as u32).to_le_bytes());
+        for index in &self.group_input_indexes {
+            result.extend_from_slice(&index.to_le_bytes());
+        }
+        result.extend_from_slice(&self.group_witness_index.to_le_bytes());
+        result.push(match self.group_witness_source {
+            Source::GroupOutput => 1,
+            _ => 0,
+        });
+        result.extend_from_slice(&(self.masks.len() as u32).to_le_bytes());
+        for (index, cursor) in &self.masks {
+            result.extend_from_slice(&(*index as u32).to_le_bytes());
+            result.extend_from_slice(&cursor.offset.to_le_bytes());
+            result.extend_from_slice(&cursor.size.to_le_bytes());
+        }
+        if let Some(cache) = &self.sighash_cache {
+            result.extend_from_slice(&cache.tx_hash);
+            for digests in [&cache.group, &cache.uncovered] {
+                result.extend_from_slice(&(digests.len() as u32).to_le_bytes());
+                for digest in digests {
+                    result.extend_from_slice(&digest.to_bytes());
+                }
+            }
+        }
+        result
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        if data.len() < CONTEXT_V1_FIXED_LEN || data[0] == 0 {
+            return Err(Error::InvalidParentContext);
+        }
+        let count = u32::from_le_bytes(data[36..40].try_into().unwrap()) as usize;
+        let indexes_end = CONTEXT_V1_FIXED_LEN + count * 4;
+        let indexes = data
+            .get(CONTEXT_V1_FIXED_LEN..indexes_end)
+            .ok_or(Error::InvalidParentContext)?;
+        let group_witness_index = if data[0] >= 2 {
+            let bytes = data
+                .get(indexes_end..indexes_end + 4)
+                .ok_or(Error::InvalidParentContext)?;
+            u32::from_le_bytes(bytes.try_into().unwrap())
+        } else {
+            0
+        };
+        let group_witness_source = if data[0] >= 3 {
+            match data.get(indexes_end + 4) {
+                Some(0) => Source::GroupInput,
+                Some(1) => Source::GroupOutput,
+                _ => return Err(Error::InvalidParentContext),
+            }
+        } else {
+            Source::GroupInput
+        };
+        let masks_start = indexes_end + 5;
+        let mut masks_end = masks_start;
+        let masks = if data[0] >= 4 {
+            let count = data
+                .get(masks_start..masks_start + 4)
+                .ok_or(Error::InvalidParentContext)?;
+            let count = u32::from_le_bytes(count.try_into().unwrap()) as usize;
+            masks_end = masks_start + 4 + count * 12;
+            data.get(masks_start + 4..masks_end)
+                .ok_or(Error::InvalidParentContext)?
+                .chunks(12)
+                .map(|c| {
+                    let n = |i: usize| u32::from_le_bytes(c[i..i + 4].try_into().unwrap());
+                    (n(0) as usize, SimpleCursor::new(n(4), n(8)))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let sighash_cache = if data[0] >= 5 {
+            let tx_hash = data
+                .get(masks_end..masks_end + 32)
+                .ok_or(Error::InvalidParentContext)?;
+            let (group, group_end) = parse_witness_digests(data, masks_end + 32)?;
+            let (uncovered, _) = parse_witness_digests(data, group_end)?;
+            Some(SighashCache {
+                tx_hash: tx_hash.try_into().unwrap(),
+                group,
+                uncovered,
+            })
+        } else {
+            None
+        };
+        Ok(Self {
+            version: data[0],
+            message: data[1..33].try_into().unwrap(),
+            witness_index: u16::from_le_bytes(data[33..35].try_into().unwrap()),
+            position: data[35],
+            group_input_indexes: indexes
+                .chunks(4)
+                .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+                .collect(),
+            group_witness_index,
+            group_witness_source,
+            masks,
+            sighash_cache,
+        })
+    }
+
+    pub fn to_arg(&self) -> String {
+        format!("{}{}", CONTEXT_ARG_PREFIX, encode(self.to_bytes()))
+    }
+
+    pub fn parse(arg: &[u8]) -> Result<Self, Error> {
+        let hex = arg
+            .strip_prefix(CONTEXT_ARG_PREFIX.as_bytes())
+            .ok_or(Error::InvalidParentContext)?;
+        let data = decode(hex).map_err(|_| Error::WrongHex)?;
+        Self::from_bytes(&data)
+    }
+}
+
+// Parses a count and the digests following it at `start`. Returns them with
+// the end offset.
+fn parse_witness_digests(data: &[u8], start: usize) -> Result<(Vec<WitnessDigest>, usize), Error> {
+    let count = data
+        .get(start..start + 4)
+        .ok_or(Error::InvalidParentContext)?;
+    let count = u32::from_le_bytes(count.try_into().unwrap()) as usize;
+    let end = start + 4 + count * WITNESS_DIGEST_LEN;
+    let digests = data
+        .get(start + 4..end)
+        .ok_or(Error::InvalidParentContext)?
+        .chunks(WITNESS_DIGEST_LEN)
+        .map(|c| WitnessDigest::from_bytes(c.try_into().unwrap()))
+        .collect();
+    Ok((digests, end))
+}
+
+fn is_context_arg(arg: &[u8]) -> bool {
+    arg.starts_with(CONTEXT_ARG_PREFIX.as_bytes())
+}
+
+/// Length of argv, without the context.
+pub fn argv_len() -> usize {
+    let argv = env::argv();
+    match argv.last() {
+        Some(arg) if is_context_arg(arg.to_bytes()) => argv.len() - 1,
+        _ => argv.len(),
+    }
+}
+
+/// Returns the context passed by ckb-combine-lock, if any.
+pub fn load_parent_context() -> Result<Option<ParentContext>, Error> {
+    match env::argv().last() {
+        Some(arg) if is_context_arg(arg.to_bytes()) => {
+            Ok(Some(ParentContext::parse(arg.to_bytes())?))
+        }
+        _ => Ok(None),
+    }
+}
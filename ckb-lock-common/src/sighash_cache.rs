@@ -0,0 +1,159 @@
+// Digests of witnesses, for sighash modes with SIGHASH_WITNESS_DIGEST, see
+// generate_sighash.rs.
+//
+// ckb-combine-lock computes them once, in the same pass as its shared message,
+// and passes them to child scripts in ParentContext. A child script signing
+// with SIGHASH_WITNESS_DIGEST then builds its message from the 40 bytes
+// digests, instead of loading and hashing every witness again.
+//
+// A digest is the length of the witness, u64 little endian, followed by the
+// blake2b of the witness with the masks zeroed.
+
+use crate::blake2b::new_blake2b;
+use crate::error::Error;
+use crate::generate_sighash_all::{calculate_inputs_len, check_masks, stream_witness_with_masks};
+use crate::simple_cursor::{get_witness_len, SimpleCursor};
+use alloc::vec::Vec;
+use blake2b_ref::Blake2b;
+use ckb_std::ckb_constants::Source;
+use ckb_std::high_level::load_tx_hash;
+
+pub const WITNESS_DIGEST_LEN: usize = 8 + 32;
+
+#[derive(Clone, Copy)]
+pub struct WitnessDigest {
+    pub len: u64,
+    pub hash: [u8; 32],
+}
+
+impl WitnessDigest {
+    pub fn to_bytes(&self) -> [u8; WITNESS_DIGEST_LEN] {
+        let mut result = [0u8; WITNESS_DIGEST_LEN];
+        result[..8].copy_from_slice(&self.len.to_le_bytes());
+        result[8..].copy_from_slice(&self.hash);
+        result
+    }
+
+    pub fn from_bytes(data: &[u8; WITNESS_DIGEST_LEN]) -> Self {
+        Self {
+            len: u64::from_le_bytes(data[..8].try_into().unwrap()),
+            hash: data[8..].try_into().unwrap(),
+        }
+    }
+}
+
+// Feeds the number of `digests`, u64 little endian, and the digests into `ctx`.
+pub fn update_witness_digests(ctx: &mut Blake2b, digests: &[WitnessDigest]) {
+    ctx.update(&(digests.len() as u64).to_le_bytes());
+    for digest in digests {
+        ctx.update(&digest.to_bytes());
+    }
+}
+
+// Returns None if there is no witness at `index`.
+fn digest_witness(
+    index: usize,
+    source: Source,
+    targets: &[(usize, SimpleCursor)],
+) -> Option<WitnessDigest> {
+    let mut ctx = new_blake2b();
+    let mut len = None;
+    // Only the length of the first chunk is the length of the witness.
+    stream_witness_with_masks(index, source, targets, |total_len, chunk| {
+        if len.is_none() {
+            len = Some(total_len as u64);
+        }
+        ctx.update(chunk);
+    });
+    let mut hash = [0u8; 32];
+    ctx.finalize(&mut hash);
+    Some(WitnessDigest { len: len?, hash })
+}
+
+// Digests of the witnesses in the script group, with `targets` zeroed. `source`
+// and `targets` are the same as the ones of `generate_sighash_all_with_masks`.
+pub fn digest_group_witnesses(
+    source: Source,
+    targets: &[(usize, SimpleCursor)],
+) -> Result<Vec<WitnessDigest>, Error> {
+    check_masks(source, targets)?;
+    // The first witness in the script group must exist.
+    get_witness_len(0, source)?;
+    let mut digests = Vec::new();
+    while let Some(digest) = digest_witness(digests.len(), source, targets) {
+        digests.push(digest);
+    }
+    Ok(digests)
+}
+
+// Digests of the witnesses not covered by inputs.
+pub fn digest_uncovered_witnesses() -> Result<Vec<WitnessDigest>, Error> {
+    let start = calculate_inputs_len()?;
+    let mut digests = Vec::new();
+    while let Some(digest) = digest_witness(start + digests.len(), Source::Input, &[]) {
+        digests.push(digest);
+    }
+    Ok(digests)
+}
+
+#[derive(Clone)]
+pub struct SighashCache {
+    pub tx_hash: [u8; 32],
+    // the witnesses in the script group, with the masks of the cache zeroed
+    pub group: Vec<WitnessDigest>,
+    // the witnesses not covered by inputs
+    pub uncovered: Vec<WitnessDigest>,
+}
+
+// Same as `generate_sighash_all_with_masks`, and the digests of the same
+// witnesses with the same `targets` zeroed. Every witness is loaded once.
+pub fn generate_sighash_all_with_cache(
+    source: Source,
+    targets: &[(usize, SimpleCursor)],
+) -> Result<([u8; 32], SighashCache), Error> {
+    check_masks(source, targets)?;
+    let mut ctx = new_blake2b();
+    let tx_hash = load_tx_hash()?;
+    ctx.update(&tx_hash);
+    // The first witness in the script group must exist.
+    get_witness_len(0, source)?;
+    let mut digest = |index: usize, source: Source, targets: &[(usize, SimpleCursor)]| {
+        let mut witness_ctx = new_blake2b();
+        let mut len = None;
+        let found = stream_witness_with_masks(index, source, targets, |total_len, chunk| {
+            if len.is_none() {
+                ctx.update(&(total_len as u64).to_le_bytes());
+                len = Some(total_len as u64);
+            }
+            ctx.update(chunk);
+            witness_ctx.update(chunk);
+        });
+        found.then(|| {
+            let mut hash = [0u8; 32];
+            witness_ctx.finalize(&mut hash);
+            WitnessDigest {
+                len: len.unwrap(),
+                hash,
+            }
+        })
+    };
+    let mut group = Vec::new();
+    while let Some(witness_digest) = digest(group.len(), source, targets) {
+        group.push(witness_digest);
+    }
+    let start = calculate_inputs_len()?;
+    let mut uncovered = Vec::new();
+    while let Some(witness_digest) = digest(start + uncovered.len(), Source::Input, &[]) {
+        uncovered.push(witness_digest);
+    }
+    let mut msg = [0u8; 32];
+    ctx.finalize(&mut msg);
+    Ok((
+        msg,
+        SighashCache {
+            tx_hash,
+            group,
+            uncovered,
+        },
+    ))
+}
@@ -1,13 +1,17 @@
 use core::fmt::Display;
+use core::ops::Range;
 
+use crate::cursor_arg::{parse_legacy_cursor, CursorArg, CursorSource, WitnessField};
 use crate::error::Error;
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, format, string::String};
 use ckb_std::{
     ckb_constants::Source,
-    syscalls::{load_witness, SysError},
+    syscalls::{load_transaction, load_witness, SysError},
 };
+use hex::{decode, encode};
 use molecule2::{Cursor, Read};
 
+#[derive(Clone)]
 pub struct SimpleCursor {
     pub offset: u32,
     pub size: u32,
@@ -25,18 +29,8 @@ impl SimpleCursor {
     }
 
     pub fn parse(str: &str) -> Result<Self, Error> {
-        let result: Vec<u32> = str
-            .split_terminator(":")
-            .into_iter()
-            .map(|n| u32::from_str_radix(n, 16).unwrap())
-            .collect::<Vec<_>>();
-        if result.len() != 2 {
-            return Err(Error::WrongHex);
-        }
-        Ok(SimpleCursor {
-            offset: result[0],
-            size: result[1],
-        })
+        let (offset, size) = parse_legacy_cursor(str.as_bytes())?;
+        Ok(SimpleCursor { offset, size })
     }
 }
 
@@ -71,6 +65,31 @@ impl Read for WitnessDataSource {
     }
 }
 
+// The whole transaction, for the parts of it without syscalls of their own.
+pub struct TransactionDataSource;
+
+impl TransactionDataSource {
+    pub fn as_cursor(self) -> Result<Cursor, Error> {
+        let mut buf = [0u8; 0];
+        let len = match load_transaction(&mut buf, 0) {
+            Ok(size) => size,
+            Err(SysError::LengthNotEnough(size)) => size,
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Cursor::new(len, Box::new(self)))
+    }
+}
+
+impl Read for TransactionDataSource {
+    fn read(&self, buf: &mut [u8], offset: usize) -> Result<usize, molecule2::Error> {
+        match load_transaction(buf, offset) {
+            Ok(size) => Ok(size),
+            Err(SysError::LengthNotEnough(_)) => Ok(buf.len()),
+            Err(_) => Err(molecule2::Error::Read),
+        }
+    }
+}
+
 pub fn get_witness_len(index: usize, source: Source) -> Result<usize, Error> {
     let mut buf = [0u8; 0];
     let len = match load_witness(&mut buf, 0, index, source) {
@@ -82,3 +101,160 @@ pub fn get_witness_len(index: usize, source: Source) -> Result<usize, Error> {
     };
     Ok(len)
 }
+
+/// Returns the number of fields in a molecule table, including the extra
+/// fields unknown to the schema. It's used to tell a newer version of a table
+/// from an older one which shares the same leading fields.
+pub fn table_field_count(cursor: &Cursor) -> Result<usize, Error> {
+    let total_size: u32 = cursor
+        .slice_by_offset(0, 4)
+        .map_err(|_| Error::Encoding)?
+        .into();
+    if total_size as usize != cursor.size {
+        return Err(Error::Encoding);
+    }
+    if total_size == 4 {
+        return Ok(0);
+    }
+    let first_offset: u32 = cursor
+        .slice_by_offset(4, 4)
+        .map_err(|_| Error::Encoding)?
+        .into();
+    if first_offset % 4 != 0 || first_offset < 8 {
+        return Err(Error::Encoding);
+    }
+    Ok(first_offset as usize / 4 - 1)
+}
+
+pub const CURSOR_ARG_PREFIX: &str = "cursor:";
+
+impl From<CursorSource> for Source {
+    fn from(source: CursorSource) -> Self {
+        match source {
+            CursorSource::GroupInput => Source::GroupInput,
+            CursorSource::GroupOutput => Source::GroupOutput,
+        }
+    }
+}
+
+impl TryFrom<Source> for CursorSource {
+    type Error = Error;
+
+    fn try_from(source: Source) -> Result<Self, Error> {
+        match source {
+            Source::GroupInput => Ok(CursorSource::GroupInput),
+            Source::GroupOutput => Ok(CursorSource::GroupOutput),
+            _ => Err(Error::InvalidCursorArg),
+        }
+    }
+}
+
+// Encoding of CursorArg in argv, and loading the witness it points to. The
+// format is described in cursor_arg.rs.
+impl CursorArg {
+    // `cursor` is in the witness at `witness_index` of `source`.
+    pub fn new(
+        source: Source,
+        witness_index: usize,
+        cursor: &Cursor,
+        field: Option<WitnessField>,
+    ) -> Result<Self, Error> {
+        let n = |v: usize| u32::try_from(v).map_err(|_| Error::InvalidCursorArg);
+        Ok(Self {
+            source: source.try_into()?,
+            witness_index: n(witness_index)?,
+            offset: n(cursor.offset)?,
+            size: n(cursor.size)?,
+            field,
+        })
+    }
+
+    pub fn to_arg(&self) -> String {
+        format!("{}{}", CURSOR_ARG_PREFIX, encode(self.to_bytes()))
+    }
+
+    // The legacy `offset:size` text doesn't tell the witness, it's the one at
+    // `legacy_index` of `legacy_source`.
+    pub fn parse(arg: &[u8], legacy_source: Source, legacy_index: usize) -> Result<Self, Error> {
+        match arg.strip_prefix(CURSOR_ARG_PREFIX.as_bytes()) {
+            Some(hex) => {
+                let data = decode(hex).map_err(|_| Error::WrongHex)?;
+                Self::from_bytes(&data)
+            }
+            None => {
+                let (offset, size) = parse_legacy_cursor(arg)?;
+                Ok(Self {
+                    source: legacy_source.try_into()?,
+                    witness_index: u32::try_from(legacy_index)
+                        .map_err(|_| Error::InvalidCursorArg)?,
+                    offset,
+                    size,
+                    field: None,
+                })
+            }
+        }
+    }
+
+    pub fn source(&self) -> Source {
+        self.source.into()
+    }
+
+    // Loads the witness and returns the cursor into it. The range must be
+    // inside the witness, and inside `field` when it's specified.
+    pub fn load(&self) -> Result<Cursor, Error> {
+        let mut cursor =
+            WitnessDataSource::new(self.source(), self.witness_index as usize).as_cursor()?;
+        let range = self.offset as usize..self.offset as usize + self.size as usize;
+        if range.end > cursor.size {
+            return Err(Error::LengthNotEnough);
+        }
+        if let Some(field) = self.field {
+            let field_range = witness_args_field(&cursor, field)?.ok_or(Error::InvalidCursorArg)?;
+            if range.start < field_range.start || range.end > field_range.end {
+                return Err(Error::InvalidCursorArg);
+            }
+        }
+        cursor.offset = range.start;
+        cursor.size = range.end - range.start;
+        Ok(cursor)
+    }
+}
+
+// Range of the bytes in `field` of the WitnessArgs `witness`. None if the
+// field is absent.
+fn witness_args_field(
+    witness: &Cursor,
+    field: WitnessField,
+) -> Result<Option<Range<usize>>, Error> {
+    if table_field_count(witness)? != 3 {
+        return Err(Error::Encoding);
+    }
+    let read_u32 = |offset: usize| -> Result<usize, Error> {
+        let n: u32 = witness
+            .slice_by_offset(offset, 4)
+            .map_err(|_| Error::Encoding)?
+            .into();
+        Ok(n as usize)
+    };
+    let i = match field {
+        WitnessField::Lock => 0,
+        WitnessField::InputType => 1,
+        WitnessField::OutputType => 2,
+    };
+    let start = read_u32(4 + i * 4)?;
+    let end = if i == 2 {
+        witness.size
+    } else {
+        read_u32(8 + i * 4)?
+    };
+    if start > end || end > witness.size {
+        return Err(Error::Encoding);
+    }
+    if start == end {
+        return Ok(None);
+    }
+    if end - start < 4 || read_u32(start)? != end - start - 4 {
+        return Err(Error::Encoding);
+    }
+    Ok(Some(start + 4..end))
+}
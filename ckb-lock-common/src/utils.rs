@@ -1,10 +1,18 @@
-use crate::{error::Error, transforming::Cell};
+use crate::{
+    combine_lock::args::{CombineLockArgs, ConfigSource},
+    error::Error,
+    transforming::Cell,
+};
 
 use alloc::{fmt, vec::Vec};
 use ckb_std::{
     ckb_constants::Source,
-    high_level::{load_cell_capacity, load_cell_data, load_cell_lock, load_cell_type},
+    high_level::{
+        load_cell_capacity, load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type,
+        QueryIter,
+    },
 };
+use log::warn;
 use molecule::prelude::Entity;
 
 pub const GLOBAL_REGISTRY_ID_LEN: usize = 32;
@@ -24,13 +32,31 @@ pub fn get_wrapped_script_hash(args: &[u8]) -> [u8; 32] {
     hash
 }
 
+// The current hash of a config cell, from its lock script args:
+// lock-wrapper: | 32 bytes global registry ID | 32 bytes wrapped script hash |
+// combine lock: parsed by CombineLockArgs, the ChildScriptConfig hash of the
+//     global registry config source. The user args after it are skipped.
+// Version 1 args with global registry are at least 66 bytes, so 64 bytes are
+// always the lock-wrapper layout.
+pub fn get_current_hash_from_args(args: &[u8]) -> Result<[u8; 32], Error> {
+    if args.len() == GLOBAL_REGISTRY_ID_LEN + WRAPPED_SCRIPT_HASH_LEN {
+        return Ok(get_wrapped_script_hash(args));
+    }
+    match CombineLockArgs::parse(args) {
+        Ok(CombineLockArgs {
+            config_source: ConfigSource::GlobalRegistry { config_hash, .. },
+            ..
+        }) => Ok(config_hash),
+        _ => {
+            warn!("config cell lock script args without global registry");
+            Err(Error::WrongGlobalRegistry)
+        }
+    }
+}
+
 pub fn get_current_hash(index: usize, source: Source) -> Result<[u8; 32], Error> {
     let lock = load_cell_lock(index, source)?;
-    let hash: [u8; 32] = lock.args().raw_data()
-        [GLOBAL_REGISTRY_ID_LEN..GLOBAL_REGISTRY_ID_LEN + WRAPPED_SCRIPT_HASH_LEN]
-        .try_into()
-        .unwrap();
-    Ok(hash)
+    get_current_hash_from_args(&lock.args().raw_data())
 }
 
 pub fn get_next_hash(index: usize, source: Source) -> Result<[u8; 32], Error> {
@@ -45,6 +71,33 @@ pub fn get_config_cell_data(index: usize, source: Source) -> Result<Vec<u8>, Err
     Ok(config_cell_data)
 }
 
+// Capacity leaving `lock_hash` in the transaction: the total capacity of the
+// inputs locked by it minus the total capacity of the outputs locked by it, 0
+// if it receives more. Both the capacity sent to other locks and the fee are
+// counted, while outputs paid by the inputs of others are not.
+pub fn net_outflow_capacity(lock_hash: &[u8; 32]) -> Result<u64, Error> {
+    let total = |source: Source| -> Result<u64, Error> {
+        let mut total: u64 = 0;
+        for (i, hash) in QueryIter::new(load_cell_lock_hash, source).enumerate() {
+            if &hash == lock_hash {
+                let capacity = load_cell_capacity(i, source)?;
+                total = total.saturating_add(capacity);
+            }
+        }
+        Ok(total)
+    };
+    Ok(total(Source::Input)?.saturating_sub(total(Source::Output)?))
+}
+
+// The fee of the transaction: the total capacity of all inputs minus the total
+// capacity of all outputs.
+pub fn transaction_fee() -> u64 {
+    let total = |source: Source| -> u64 {
+        QueryIter::new(load_cell_capacity, source).fold(0u64, |total, c| total.saturating_add(c))
+    };
+    total(Source::Input).saturating_sub(total(Source::Output))
+}
+
 pub fn capacity_unchanged(input_index: usize, output_index: usize) -> bool {
     let i = load_cell_capacity(input_index, Source::Input).unwrap();
     let o = load_cell_capacity(output_index, Source::Output).unwrap();
@@ -0,0 +1,76 @@
+// Loads ckb-auth at cell_deps[1] by dynamic linking with different hash types,
+// i.e. as different libraries of the same size, one after another. Built with
+// feature "dl-context-256k", the context can't hold all of them: exactly
+// capacity / library size of them are loaded before DLMemoryNotEnough.
+use crate::error::Error;
+use ckb_lock_common::ckb_auth::{CKBDLLoader, CkbAuthError};
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::core::ScriptHashType,
+    high_level::{load_cell_data_hash, load_cell_type_hash},
+};
+use core::result::Result;
+use log::info;
+
+const AUTH_CELL_DEP_INDEX: usize = 1;
+const EXPORTED_FUNC_NAME: &str = "ckb_auth_validate";
+
+// Only looked up, never called.
+type CkbAuthValidate = unsafe extern "C" fn() -> i32;
+
+fn load(
+    loader: &mut CKBDLLoader,
+    code_hash: &[u8; 32],
+    hash_type: ScriptHashType,
+) -> Result<(), CkbAuthError> {
+    loader.get_validate_func::<CkbAuthValidate>(code_hash, hash_type, EXPORTED_FUNC_NAME)?;
+    Ok(())
+}
+
+pub fn main() -> Result<(), Error> {
+    let data_hash = load_cell_data_hash(AUTH_CELL_DEP_INDEX, Source::CellDep)?;
+    let type_hash =
+        load_cell_type_hash(AUTH_CELL_DEP_INDEX, Source::CellDep)?.ok_or(Error::ItemMissing)?;
+    let libraries = [
+        (data_hash, ScriptHashType::Data1),
+        (type_hash, ScriptHashType::Type),
+        (data_hash, ScriptHashType::Data),
+    ];
+
+    let loader = CKBDLLoader::get();
+    let mut library_size = 0;
+    let mut loaded = 0;
+    for (code_hash, hash_type) in libraries {
+        let remaining = loader.remaining_capacity();
+        info!("{} of {} bytes remaining", remaining, loader.capacity());
+        match load(loader, &code_hash, hash_type) {
+            Err(CkbAuthError::DLMemoryNotEnough) => {
+                if loaded == 0 {
+                    return Err(Error::DLMemoryNotEnough);
+                }
+                // all libraries are the same binary
+                let expected = loader.capacity() / library_size;
+                info!("{} libraries loaded, {} expected", loaded, expected);
+                if loaded != expected {
+                    return Err(Error::UnexpectedLibraryCount);
+                }
+                // the libraries loaded before are still usable
+                load(loader, &data_hash, ScriptHashType::Data1)?;
+                return Ok(());
+            }
+            ret => ret?,
+        }
+        if loader.remaining_capacity() >= remaining {
+            return Err(Error::CapacityNotUsed);
+        }
+        library_size = remaining - loader.remaining_capacity();
+        loaded += 1;
+        // loaded already
+        let remaining = loader.remaining_capacity();
+        load(loader, &code_hash, hash_type)?;
+        if loader.remaining_capacity() != remaining {
+            return Err(Error::CapacityUsedTwice);
+        }
+    }
+    Err(Error::NotExhausted)
+}
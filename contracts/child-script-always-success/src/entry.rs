@@ -1,7 +1,13 @@
 use crate::error::Error;
 use core::result::Result;
 
+#[cfg(not(feature = "dl-loader-test"))]
 pub fn main() -> Result<(), Error> {
     log::info!("always success!");
     Ok(())
 }
+
+#[cfg(feature = "dl-loader-test")]
+pub fn main() -> Result<(), Error> {
+    crate::dl_loader_test::main()
+}
@@ -1,3 +1,4 @@
+use ckb_lock_common::ckb_auth::CkbAuthError;
 use ckb_std::error::SysError;
 
 /// Error
@@ -7,6 +8,18 @@ pub enum Error {
     ItemMissing,
     LengthNotEnough,
     Encoding,
+    // errors of dl_loader_test.rs
+    LoadDLError,
+    DLMemoryNotEnough,
+    // a newly loaded library doesn't take space
+    CapacityNotUsed,
+    // a library loaded before takes space again
+    CapacityUsedTwice,
+    // the number of libraries loaded before running out of space is not the
+    // one expected from the library size
+    UnexpectedLibraryCount,
+    // all libraries fit in the context
+    NotExhausted,
 }
 
 impl From<SysError> for Error {
@@ -22,3 +35,12 @@ impl From<SysError> for Error {
         }
     }
 }
+
+impl From<CkbAuthError> for Error {
+    fn from(value: CkbAuthError) -> Self {
+        match value {
+            CkbAuthError::DLMemoryNotEnough => Self::DLMemoryNotEnough,
+            _ => Self::LoadDLError,
+        }
+    }
+}
@@ -8,6 +8,8 @@
 #![cfg_attr(not(test), no_main)]
 
 // define modules
+#[cfg(feature = "dl-loader-test")]
+mod dl_loader_test;
 mod entry;
 mod error;
 
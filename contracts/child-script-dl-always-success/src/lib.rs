@@ -0,0 +1,19 @@
+//! An always success child script of combine lock built with "exec-fallback"
+//! feature, run by dynamic linking. Unlike child-script-always-success, it's a
+//! shared library exporting `ckb_child_script_entry`, see runner.rs in
+//! ckb-lock-common.
+
+#![no_std]
+
+use core::ffi::c_char;
+
+#[no_mangle]
+pub unsafe extern "C" fn ckb_child_script_entry(_argc: i32, _argv: *const *const c_char) -> i8 {
+    0
+}
+
+#[panic_handler]
+fn panic_handler(_: &core::panic::PanicInfo) -> ! {
+    // exit syscall. Without ckb-std, which requires a global allocator.
+    unsafe { core::arch::asm!("ecall", in("a0") -1, in("a7") 93, options(noreturn)) }
+}
@@ -1,11 +1,16 @@
 extern crate alloc;
 use crate::error::Error;
-use alloc::vec::Vec;
-use ckb_lock_common::ckb_auth::{
-    ckb_auth, AuthAlgorithmIdType, CkbAuthType, CkbEntryType, EntryCategoryType,
+use alloc::{vec, vec::Vec};
+use ckb_lock_common::auth_args::{AuthArgs, WitnessLayout};
+use ckb_lock_common::ckb_auth::{ckb_auth, CkbEntryType, EntryCategoryType};
+use ckb_lock_common::cursor_arg::CursorArg;
+use ckb_lock_common::envelope::{check_actions, generate_signing_message, parse_envelope};
+use ckb_lock_common::generate_sighash::{
+    generate_sighash, generate_sighash_with_cache, group_input_indexes, SighashMode,
 };
 use ckb_lock_common::generate_sighash_all::generate_sighash_all;
 use ckb_lock_common::generated::blockchain::WitnessArgs;
+use ckb_lock_common::parent_context::{argv_len, load_parent_context};
 use ckb_lock_common::simple_cursor::{SimpleCursor, WitnessDataSource};
 use ckb_std::env;
 use ckb_std::{
@@ -17,6 +22,7 @@ use core::result::Result;
 use log::{info, warn};
 use molecule2::Cursor;
 
+// The ckb-auth entry used when args are in the legacy 21-byte layout.
 static DL_CODE_HASH: [u8; 32] = [
     0xD4, 0x0C, 0xCE, 0x7F, 0xDF, 0xF8, 0x24, 0xF6, 0x31, 0x7B, 0x31, 0x09, 0x94, 0xF5, 0x88, 0x73,
     0x69, 0xD7, 0xEA, 0x49, 0x93, 0x4D, 0x3D, 0x7A, 0xD7, 0xA2, 0x27, 0xC4, 0xE5, 0x4F, 0xDC, 0xED,
@@ -24,7 +30,7 @@ static DL_CODE_HASH: [u8; 32] = [
 static DL_HASH_TYPE: ScriptHashType = ScriptHashType::Data1;
 
 fn parse_args() -> Result<Bytes, Error> {
-    let len = ckb_std::env::argv().len();
+    let len = argv_len();
     if len == 0 {
         let script = load_script()?;
         return Ok(script.args().unpack());
@@ -37,23 +43,32 @@ fn parse_args() -> Result<Bytes, Error> {
     return Err(Error::WrongFormat);
 }
 
-fn parse_witness() -> Result<Cursor, Error> {
-    let len = env::argv().len();
-    let data_source = WitnessDataSource::new(Source::GroupInput, 0);
-    let mut cursor = data_source.as_cursor().unwrap();
+// Returns the cursor and where the witness is. A legacy cursor in argv points
+// into the witness at `group_witness_index` of `group_witness_source`, while a
+// CursorArg tells the witness itself.
+fn parse_witness(
+    group_witness_source: Source,
+    group_witness_index: usize,
+) -> Result<(Cursor, Source, usize), Error> {
+    let len = argv_len();
     if len == 0 {
+        let data_source = WitnessDataSource::new(group_witness_source, group_witness_index);
+        let cursor = data_source
+            .as_cursor()
+            .map_err(|_| Error::IndexOutOfBound)?;
         let witness_args: WitnessArgs = cursor.into();
-        let lock = witness_args.lock().unwrap();
-        return Ok(lock);
+        let lock = witness_args.lock().ok_or(Error::WrongFormat)?;
+        return Ok((lock, group_witness_source, group_witness_index));
     }
     if len == 2 {
-        let arg = env::argv()[1].to_str().unwrap();
-        let simple_cursor = SimpleCursor::parse(arg).unwrap();
-
-        cursor.offset = simple_cursor.offset as usize;
-        cursor.size = simple_cursor.size as usize;
-        cursor.validate();
-        return Ok(cursor);
+        let arg = CursorArg::parse(
+            env::argv()[1].to_bytes(),
+            group_witness_source,
+            group_witness_index,
+        )
+        .map_err(|_| Error::WrongFormat)?;
+        let cursor = arg.load().map_err(|_| Error::WrongFormat)?;
+        return Ok((cursor, arg.source(), arg.witness_index as usize));
     }
     warn!("parse_witness failed, wrong format");
     return Err(Error::WrongFormat);
@@ -64,7 +79,13 @@ pub fn main() -> Result<(), Error> {
     let execution_args = parse_args()?;
     info!("child-script-example execution_args = {:?}", execution_args);
     let execution_args_slice = execution_args.as_ref();
-    let cursor = parse_witness()?;
+    let context = load_parent_context().map_err(|_| Error::WrongFormat)?;
+    let (group_witness_source, group_witness_index) =
+        context.as_ref().map_or((Source::GroupInput, 0), |c| {
+            (c.group_witness_source, c.group_witness_index as usize)
+        });
+    let (cursor, group_witness_source, group_witness_index) =
+        parse_witness(group_witness_source, group_witness_index)?;
     let simple_cursor = SimpleCursor::new_from_cursor(&cursor);
     let witness_args_lock: Vec<u8> = cursor.try_into().unwrap();
     info!(
@@ -72,25 +93,79 @@ pub fn main() -> Result<(), Error> {
         witness_args_lock.len(),
         &witness_args_lock
     );
-    if execution_args_slice.len() != 21 {
-        warn!(
-            "args length is wrong, 21 is expected: {}",
-            execution_args_slice.len()
-        );
-        return Err(Error::WrongFormat);
-    }
-    let auth_id = execution_args_slice[0] as u8;
-    let pubkey_hash: [u8; 20] = execution_args_slice[1..].try_into().unwrap();
-    let message = generate_sighash_all(&simple_cursor).map_err(|_| Error::GeneratedMsgError)?;
-    let id = CkbAuthType {
-        algorithm_id: AuthAlgorithmIdType::try_from(auth_id)?,
-        pubkey_hash: pubkey_hash,
+    let auth_args = AuthArgs::parse(execution_args_slice)?;
+    let (sighash_mode, actions_message, signature) = match auth_args.witness_layout {
+        WitnessLayout::Signature => (None, None, &witness_args_lock[..]),
+        WitnessLayout::SighashMode => {
+            let (mode, len) =
+                SighashMode::parse(&witness_args_lock).map_err(|_| Error::WrongFormat)?;
+            (Some(mode), None, &witness_args_lock[len..])
+        }
+        // The whole SighashAll is zeroed in the sighash below, the actions are
+        // signed by the signing message of the envelope.
+        WitnessLayout::Envelope => {
+            let envelope = parse_envelope(&witness_args_lock).map_err(|_| Error::WrongFormat)?;
+            (None, Some(envelope.message()), envelope.seal().raw_data())
+        }
+    };
+    // The message computed by ckb-combine-lock is reused when it's available.
+    let message = match (sighash_mode, context) {
+        (None, Some(context)) => context.message,
+        (None, None) => {
+            generate_sighash_all(&simple_cursor).map_err(|_| Error::GeneratedMsgError)?
+        }
+        // The whole witness is zeroed, including the sighash mode. It's signed
+        // by the sighash mode in the message. SIGHASH_ALL with the masks of
+        // ckb-combine-lock is the message computed by it.
+        (Some(mode), Some(context)) if mode.is_sighash_all() && !context.masks.is_empty() => {
+            context.message
+        }
+        // The whole script group is signed, the same as without ParentContext.
+        // `group_input_indexes` in it are only the inputs unlocked by this
+        // witness.
+        (Some(mode), Some(context)) => {
+            let group_input_indexes =
+                group_input_indexes().map_err(|_| Error::GeneratedMsgError)?;
+            // The witness digests of ckb-combine-lock are computed with its
+            // masks, they can't be used with other masks.
+            let (masks, cache) = if context.masks.is_empty() {
+                (vec![(group_witness_index, simple_cursor)], None)
+            } else {
+                (context.masks, context.sighash_cache)
+            };
+            generate_sighash_with_cache(
+                &mode,
+                &group_input_indexes,
+                group_witness_source,
+                &masks,
+                cache.as_ref(),
+            )
+            .map_err(|_| Error::GeneratedMsgError)?
+        }
+        (Some(mode), None) => {
+            let group_input_indexes =
+                group_input_indexes().map_err(|_| Error::GeneratedMsgError)?;
+            generate_sighash(
+                &mode,
+                &group_input_indexes,
+                Source::GroupInput,
+                &[(0, simple_cursor)],
+            )
+            .map_err(|_| Error::GeneratedMsgError)?
+        }
     };
-    let entry = CkbEntryType {
+    let message = match actions_message {
+        Some(actions_message) => {
+            check_actions(&actions_message).map_err(|_| Error::UnknownAction)?;
+            generate_signing_message(&actions_message, &message)
+        }
+        None => message,
+    };
+    let entry = auth_args.entry.unwrap_or(CkbEntryType {
         code_hash: DL_CODE_HASH,
         hash_type: DL_HASH_TYPE,
         entry_category: EntryCategoryType::DynamicLinking,
-    };
-    ckb_auth(&entry, &id, witness_args_lock.as_ref(), &message)?;
+    });
+    ckb_auth(&entry, &auth_args.id, signature, &message)?;
     Ok(())
 }
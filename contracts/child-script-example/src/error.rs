@@ -14,6 +14,8 @@ pub enum Error {
     GeneratedMsgError,
     LoadDLError,
     RunAuthError,
+    DLMemoryNotEnough,
+    UnknownAction,
 }
 
 impl From<SysError> for Error {
@@ -38,6 +40,9 @@ impl From<CkbAuthError> for Error {
             LoadDLError => Self::LoadDLError,
             LoadDLFuncError => Self::LoadDLError,
             RunDLError => Self::RunAuthError,
+            RunSpawnError => Self::RunAuthError,
+            DLMemoryNotEnough => Self::DLMemoryNotEnough,
+            InvalidArgs => Self::WrongFormat,
             _ => panic!("unexpected error"),
         }
     }
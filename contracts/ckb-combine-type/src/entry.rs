@@ -0,0 +1,20 @@
+use crate::error::ExitCode;
+use ckb_lock_common::combine_lock::{combine_lock_entry, ScriptType};
+use ckb_std::{
+    ckb_constants::Source,
+    high_level::{load_cell_capacity, QueryIter},
+};
+
+// The same as combine lock, but as a type script: minting and burning cells
+// with it are gated by child scripts. CombineLockWitness is in `input_type`,
+// or `output_type` if there is no group input.
+//
+// A transaction with as many group outputs as group inputs only moves the
+// cells, which is up to their locks, so no child script is run for it.
+pub fn main() -> Result<(), ExitCode> {
+    let count = |source| QueryIter::new(load_cell_capacity, source).count();
+    if count(Source::GroupInput) == count(Source::GroupOutput) {
+        return Ok(());
+    }
+    combine_lock_entry(ScriptType::Type)
+}
@@ -0,0 +1,2 @@
+// The error codes are shared with the combine lock.
+pub use ckb_lock_common::combine_lock::error::{Error, ExitCode};
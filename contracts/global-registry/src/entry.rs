@@ -3,7 +3,7 @@ use ckb_lock_common::{
     blake2b::new_blake2b,
     transforming::{self, BatchTransformingStatus},
     utils::{
-        config_cell_unchanged, get_current_hash, get_next_hash, get_wrapped_script_hash,
+        config_cell_unchanged, get_current_hash, get_current_hash_from_args, get_next_hash,
         lock_unchanged, type_unchanged,
     },
 };
@@ -72,7 +72,7 @@ fn validate_init_values() -> Result<(), Error> {
     let script = load_cell_lock(0, Source::GroupOutput)?;
     let args = script.args();
     let slice = args.raw_data();
-    let current_hash = get_wrapped_script_hash(&slice);
+    let current_hash = get_current_hash_from_args(&slice)?;
     if current_hash != [0u8; 32] {
         return Err(Error::InvalidInitValues);
     }
@@ -94,7 +94,7 @@ fn validate_linked_list() -> Result<(), Error> {
     let iter = QueryIter::new(load_cell_type_hash, Source::Input);
     for (i, hash) in iter.enumerate() {
         if hash == Some(current_script_hash) {
-            let current_hash = get_current_hash(i, Source::Input).unwrap();
+            let current_hash = get_current_hash(i, Source::Input)?;
             let next_hash = get_next_hash(i, Source::Input).unwrap();
             if current_hash >= next_hash {
                 warn!(
@@ -109,7 +109,7 @@ fn validate_linked_list() -> Result<(), Error> {
     let iter = QueryIter::new(load_cell_type_hash, Source::Output);
     for (i, hash) in iter.enumerate() {
         if hash == Some(current_script_hash) {
-            let current_hash = get_current_hash(i, Source::Output).unwrap();
+            let current_hash = get_current_hash(i, Source::Output)?;
             let next_hash = get_next_hash(i, Source::Output).unwrap();
             if current_hash >= next_hash {
                 warn!(
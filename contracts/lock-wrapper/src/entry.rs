@@ -1,11 +1,12 @@
 extern crate alloc;
 use crate::error::Error;
-use alloc::{ffi::CString, format, vec::Vec};
+use alloc::{ffi::CString, vec::Vec};
 use ckb_combine_lock_types::lock_wrapper::ConfigCellDataOptReader;
 use ckb_lock_common::{
     blake2b::hash,
+    cursor_arg::{CursorArg, WitnessField},
     generated::{blockchain::WitnessArgs, lock_wrapper::LockWrapperWitness},
-    simple_cursor::{SimpleCursor, WitnessDataSource},
+    simple_cursor::WitnessDataSource,
     transforming::{self, BatchTransformingStatus},
     utils::{
         config_cell_unchanged, get_current_hash, get_global_registry_id, get_next_hash,
@@ -148,7 +149,7 @@ fn validate_config_cell(global_registry_id: &[u8; 32]) -> Result<(), Error> {
     let iter = QueryIter::new(load_cell_type_hash, Source::Input);
     for (i, hash) in iter.enumerate() {
         if hash == Some(*global_registry_id) {
-            let current_hash = get_current_hash(i, Source::Input).unwrap();
+            let current_hash = get_current_hash(i, Source::Input)?;
             let next_hash = get_next_hash(i, Source::Input).unwrap();
             if current_hash >= next_hash {
                 warn!(
@@ -164,7 +165,7 @@ fn validate_config_cell(global_registry_id: &[u8; 32]) -> Result<(), Error> {
     let iter = QueryIter::new(load_cell_type_hash, Source::Output);
     for (i, hash) in iter.enumerate() {
         if hash == Some(*global_registry_id) {
-            let current_hash = get_current_hash(i, Source::Output).unwrap();
+            let current_hash = get_current_hash(i, Source::Output)?;
             let next_hash = get_next_hash(i, Source::Output).unwrap();
             if current_hash >= next_hash {
                 warn!(
@@ -232,7 +233,8 @@ fn validate_config_cell(global_registry_id: &[u8; 32]) -> Result<(), Error> {
 }
 
 ///
-/// execute wrapped script with no config cell
+/// execute wrapped script with no config cell. Only the first witness in the
+/// script group is passed to the wrapped script, so it unlocks all the inputs.
 ///
 fn exec_no_config(wrapped_script_hash: [u8; 32]) -> Result<(), Error> {
     let data_source = WitnessDataSource::new(Source::GroupInput, 0);
@@ -255,8 +257,13 @@ fn exec_no_config(wrapped_script_hash: [u8; 32]) -> Result<(), Error> {
     };
     let args_bytes: Vec<u8> = wrapped_script.args().try_into().unwrap();
     let arg0 = encode_hex(&args_bytes);
-    let wrapped_witness_cursor = SimpleCursor::new_from_cursor(&wrapped_witness);
-    let arg1 = CString::new(format!("{}", wrapped_witness_cursor)).unwrap();
+    let wrapped_witness_cursor = CursorArg::new(
+        Source::GroupInput,
+        0,
+        &wrapped_witness,
+        Some(WitnessField::Lock),
+    )?;
+    let arg1 = CString::new(wrapped_witness_cursor.to_arg()).map_err(|_| Error::WrongFormat)?;
     debug!("arg0: {:?}", arg0);
     debug!("arg1: {:?}", arg1);
 
@@ -290,8 +297,13 @@ fn exec_with_config(config_cell_data: &[u8]) -> Result<(), Error> {
     };
 
     let arg0 = encode_hex(wrapped_script.args().raw_data());
-    let wrapped_witness_cursor = SimpleCursor::new_from_cursor(&wrapped_witness);
-    let arg1 = CString::new(format!("{}", wrapped_witness_cursor)).unwrap();
+    let wrapped_witness_cursor = CursorArg::new(
+        Source::GroupInput,
+        0,
+        &wrapped_witness,
+        Some(WitnessField::Lock),
+    )?;
+    let arg1 = CString::new(wrapped_witness_cursor.to_arg()).map_err(|_| Error::WrongFormat)?;
     let arg2 = encode_hex(script_config.raw_data());
 
     debug!("arg0: {:?}", arg0);
@@ -0,0 +1,43 @@
+// Signing message of a building packet, the SighashAll witness in cobuild.mol.
+// It's shared by the verification in contracts and the signing off-chain, so
+// both sides hash the same bytes.
+//
+// The signing message is blake2b, personalized with COBUILD_PERSONALIZATION,
+// of:
+// | Message in molecule | 32 bytes sighash |
+// sighash: the message of generate_sighash_all, with the whole SighashAll
+// witness zeroed
+// Every action must be for a script in the transaction: the lock or type script
+// of an input, or the type script of an output.
+
+use super::cobuild::{MessageReader, SighashAllReader};
+use molecule::error::VerificationResult;
+use molecule::prelude::*;
+
+pub const COBUILD_PERSONALIZATION: &[u8] = b"ckb-tcob-sighash";
+
+pub fn parse_envelope(witness: &[u8]) -> VerificationResult<SighashAllReader> {
+    SighashAllReader::from_slice(witness)
+}
+
+// Feeds the signing message into `update` of a blake2b hasher built with
+// COBUILD_PERSONALIZATION.
+pub fn update_signing_message<F: FnMut(&[u8])>(
+    message: &MessageReader,
+    sighash: &[u8; 32],
+    mut update: F,
+) {
+    update(message.as_slice());
+    update(sighash);
+}
+
+// Returns the index of the first action whose script hash is not in
+// `script_hashes`.
+pub fn find_unknown_action(message: &MessageReader, script_hashes: &[[u8; 32]]) -> Option<usize> {
+    message.actions().iter().position(|action| {
+        let script_hash = action.script_hash();
+        !script_hashes
+            .iter()
+            .any(|hash| &hash[..] == script_hash.raw_data())
+    })
+}
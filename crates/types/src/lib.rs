@@ -1,6 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod cobuild;
 pub mod combine_lock;
+pub mod envelope;
 pub mod lock_wrapper;
 pub mod primitives;
 
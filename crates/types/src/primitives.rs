@@ -45,3 +45,9 @@ impl From<super::combine_lock::ChildScript> for Script {
         Script::new_unchecked(value.as_bytes())
     }
 }
+
+impl From<super::combine_lock::ChildScriptConfigV2> for super::combine_lock::ChildScriptConfig {
+    fn from(value: super::combine_lock::ChildScriptConfigV2) -> Self {
+        super::combine_lock::ChildScriptConfig::new_unchecked(value.as_bytes())
+    }
+}
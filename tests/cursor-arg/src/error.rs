@@ -0,0 +1,5 @@
+#[derive(Debug)]
+pub enum Error {
+    WrongHex,
+    InvalidCursorArg,
+}
@@ -0,0 +1,180 @@
+// Native tests of cursor_arg.rs in ckb-lock-common, including fuzzing of the
+// parsers of the cursor in argv.
+#![allow(dead_code)]
+
+#[path = "../../../ckb-lock-common/src/cursor_arg.rs"]
+mod cursor_arg;
+mod error;
+
+#[cfg(test)]
+use cursor_arg::{parse_legacy_cursor, CURSOR_ARG_LEN};
+use cursor_arg::{CursorArg, CursorSource, WitnessField};
+
+fn cursor_arg(
+    witness_index: u32,
+    offset: u32,
+    size: u32,
+    field: Option<WitnessField>,
+) -> CursorArg {
+    CursorArg {
+        source: CursorSource::GroupInput,
+        witness_index,
+        offset,
+        size,
+        field,
+    }
+}
+
+#[test]
+fn test_cursor_arg() {
+    let arg = cursor_arg(2, 0x10, 0x41, Some(WitnessField::Lock));
+    let bytes = arg.to_bytes();
+    assert_eq!(bytes, [1, 0, 2, 0, 0, 0, 0x10, 0, 0, 0, 0x41, 0, 0, 0, 1]);
+    assert_eq!(CursorArg::from_bytes(&bytes).unwrap(), arg);
+
+    let mut arg = cursor_arg(0, 0, 0, None);
+    arg.source = CursorSource::GroupOutput;
+    assert_eq!(CursorArg::from_bytes(&arg.to_bytes()).unwrap(), arg);
+}
+
+#[test]
+fn test_cursor_arg_invalid() {
+    let bytes = cursor_arg(0, 0x10, 0x41, None).to_bytes();
+    // length
+    assert!(CursorArg::from_bytes(&[]).is_err());
+    assert!(CursorArg::from_bytes(&bytes[..CURSOR_ARG_LEN - 1]).is_err());
+    assert!(CursorArg::from_bytes(&[&bytes[..], &[0]].concat()).is_err());
+    // version, source and field
+    for (i, v) in [(0, 0), (0, 2), (1, 2), (14, 4)] {
+        let mut data = bytes;
+        data[i] = v;
+        assert!(CursorArg::from_bytes(&data).is_err());
+    }
+    // offset + size overflows
+    let mut data = cursor_arg(0, u32::MAX, 0, None).to_bytes();
+    assert!(CursorArg::from_bytes(&data).is_ok());
+    data[10] = 1;
+    assert!(CursorArg::from_bytes(&data).is_err());
+}
+
+#[test]
+fn test_legacy_cursor() {
+    assert_eq!(parse_legacy_cursor(b"10:41").unwrap(), (0x10, 0x41));
+    assert_eq!(parse_legacy_cursor(b"aB:0").unwrap(), (0xab, 0));
+    assert_eq!(parse_legacy_cursor(b"ffffffff:0").unwrap(), (u32::MAX, 0));
+    for text in [
+        &b""[..],
+        b":",
+        b"10",
+        b"10:",
+        b":41",
+        b"10:41:",
+        b"10:41:0",
+        b"+10:41",
+        b"10:-41",
+        b" 10:41",
+        b"10:4g",
+        b"100000000:0",
+        b"ffffffff:1",
+        b"10:\xff",
+    ] {
+        assert!(parse_legacy_cursor(text).is_err(), "{:?}", text);
+    }
+}
+
+// xorshift64, deterministic random numbers for fuzzing without extra crates
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bytes(&mut self, len: usize, alphabet: &[u8]) -> Vec<u8> {
+        (0..len)
+            .map(|_| alphabet[self.next() as usize % alphabet.len()])
+            .collect()
+    }
+}
+
+#[test]
+fn fuzz_cursor_arg() {
+    let all: Vec<u8> = (0..=255).collect();
+    let mut rng = Rng(0x1234_5678_9abc_def0);
+    for _ in 0..100_000 {
+        let len = rng.next() as usize % (CURSOR_ARG_LEN + 3);
+        let data = rng.bytes(len, &all);
+        // Never panics, and the accepted ones are canonical.
+        if let Ok(arg) = CursorArg::from_bytes(&data) {
+            assert_eq!(&arg.to_bytes()[..], &data[..]);
+        }
+    }
+    for _ in 0..100_000 {
+        let field = [
+            None,
+            Some(WitnessField::Lock),
+            Some(WitnessField::OutputType),
+        ];
+        let mut arg = cursor_arg(
+            rng.next() as u32,
+            rng.next() as u32 / 2,
+            rng.next() as u32 / 2,
+            field[rng.next() as usize % field.len()],
+        );
+        if rng.next() % 2 == 0 {
+            arg.source = CursorSource::GroupOutput;
+        }
+        let mut data = arg.to_bytes();
+        assert_eq!(CursorArg::from_bytes(&data).unwrap(), arg);
+        // flip a byte
+        let i = rng.next() as usize % CURSOR_ARG_LEN;
+        data[i] ^= (rng.next() % 255 + 1) as u8;
+        if let Ok(mutated) = CursorArg::from_bytes(&data) {
+            assert_ne!(mutated, arg);
+            assert_eq!(mutated.to_bytes(), data);
+        }
+    }
+}
+
+#[test]
+fn fuzz_legacy_cursor() {
+    let mut rng = Rng(0x0fed_cba9_8765_4321);
+    for _ in 0..100_000 {
+        let len = rng.next() as usize % 24;
+        let text = rng.bytes(len, b"0123456789abcdefABCDEF:+- gx\xff");
+        // Never panics, and the accepted ones are formatted back the same.
+        if let Ok((offset, size)) = parse_legacy_cursor(&text) {
+            let expected = format!("{:x}:{:x}", offset, size);
+            let text = String::from_utf8(text).unwrap().to_lowercase();
+            let trimmed: Vec<&str> = text.split(':').map(|n| n.trim_start_matches('0')).collect();
+            let trimmed = format!(
+                "{}:{}",
+                if trimmed[0].is_empty() {
+                    "0"
+                } else {
+                    trimmed[0]
+                },
+                if trimmed[1].is_empty() {
+                    "0"
+                } else {
+                    trimmed[1]
+                }
+            );
+            assert_eq!(trimmed, expected);
+        }
+    }
+    for _ in 0..100_000 {
+        let offset = rng.next() as u32 / 2;
+        let size = rng.next() as u32 / 2;
+        let text = format!("{:x}:{:x}", offset, size);
+        assert_eq!(
+            parse_legacy_cursor(text.as_bytes()).unwrap(),
+            (offset, size)
+        );
+    }
+}
+
+fn main() {}